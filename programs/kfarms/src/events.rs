@@ -0,0 +1,478 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct InitializeGlobalConfigEvent {
+    pub global_config: Pubkey,
+    pub global_admin: Pubkey,
+    pub treasury_vaults_authority: Pubkey,
+}
+
+#[event]
+pub struct GlobalConfigUpdatedEvent {
+    pub global_config: Pubkey,
+    pub mode: u8,
+    pub value: [u8; 32],
+    pub pending_global_admin: Pubkey,
+    pub treasury_fee_bps: u64,
+}
+
+#[event]
+pub struct GlobalConfigAdminUpdatedEvent {
+    pub global_config: Pubkey,
+    pub prev_global_admin: Pubkey,
+    pub new_global_admin: Pubkey,
+}
+
+#[event]
+pub struct InitializeFarmEvent {
+    pub farm_state: Pubkey,
+    pub farm_admin: Pubkey,
+    pub global_config: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub farm_vault: Pubkey,
+    pub delegate_authority: Pubkey,
+    pub ts: u64,
+}
+
+#[event]
+pub struct InitializeRewardEvent {
+    pub farm_state: Pubkey,
+    pub reward_index: u64,
+    pub reward_mint: Pubkey,
+    pub reward_vault: Pubkey,
+    pub token_program: Pubkey,
+    pub ts: u64,
+}
+
+#[event]
+pub struct AddRewardEvent {
+    pub farm_state: Pubkey,
+    pub payer: Pubkey,
+    pub reward_index: u64,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    pub transfer_fee: u64,
+    pub rewards_available_before: u64,
+    pub rewards_available_after: u64,
+    pub reward_per_share_scaled: u128,
+    pub ts: u64,
+}
+
+#[event]
+pub struct WithdrawRewardEvent {
+    pub farm_state: Pubkey,
+    pub reward_index: u64,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    pub transfer_fee: u64,
+    pub rewards_available_before: u64,
+    pub rewards_available_after: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct FarmConfigUpdatedEvent {
+    pub farm_state: Pubkey,
+    pub signer: Pubkey,
+    pub mode: u16,
+    pub data: Vec<u8>,
+    pub ts: u64,
+}
+
+#[event]
+pub struct FarmAdminUpdatedEvent {
+    pub farm_state: Pubkey,
+    pub prev_farm_admin: Pubkey,
+    pub new_farm_admin: Pubkey,
+}
+
+#[event]
+pub struct SecondDelegatedAuthorityUpdatedEvent {
+    pub farm_state: Pubkey,
+    pub prev_second_delegated_authority: Pubkey,
+    pub new_second_delegated_authority: Pubkey,
+}
+
+#[event]
+pub struct InitializeUserEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub delegatee: Pubkey,
+    pub user_id: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct CloseUserStateEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+    pub num_users: u64,
+}
+
+#[event]
+pub struct RefreshFarmEvent {
+    pub farm_state: Pubkey,
+    pub rewards_per_share_scaled: Vec<u128>,
+    pub rewards_available: Vec<u64>,
+    pub ts: u64,
+}
+
+#[event]
+pub struct RefreshUserStateEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub active_stake_scaled: u128,
+    pub pending_deposit_stake_scaled: u128,
+    pub rewards_issued_unclaimed: Vec<u64>,
+    pub ts: u64,
+}
+
+#[event]
+pub struct RefreshUserBoostEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub lock_end_ts: u64,
+    pub boosted_stake_scaled: u128,
+    pub total_boosted_stake_scaled: u128,
+    pub ts: u64,
+}
+
+#[event]
+pub struct StakeEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub active_stake_scaled_before: u128,
+    pub active_stake_scaled_after: u128,
+    pub pending_deposit_stake_scaled_before: u128,
+    pub pending_deposit_stake_scaled_after: u128,
+    pub pending_deposit_stake_ts: u64,
+    pub boosted_stake_scaled_after: u128,
+    pub lock_end_ts: u64,
+    pub total_staked_amount: u64,
+    pub total_active_stake_scaled: u128,
+    pub total_pending_amount: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct StakeForEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub active_stake_scaled_before: u128,
+    pub active_stake_scaled_after: u128,
+    pub pending_deposit_stake_scaled_before: u128,
+    pub pending_deposit_stake_scaled_after: u128,
+    pub pending_deposit_stake_ts: u64,
+    pub total_staked_amount: u64,
+    pub total_active_stake_scaled: u128,
+    pub total_pending_amount: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct SetStakeDelegatedEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub delegate_authority: Pubkey,
+    pub stake_before: u64,
+    pub stake_after: u64,
+    pub total_staked_amount: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct UnstakeEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub stake_shares_scaled: u128,
+    pub amount_unstaked: u64,
+    pub penalty_amount: u64,
+    pub active_stake_scaled_after: u128,
+    pub pending_withdrawal_unstake_scaled_after: u128,
+    pub pending_withdrawal_unstake_ts: u64,
+    pub total_staked_amount: u64,
+    pub total_pending_amount: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct SlashedEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub penalty_amount: u64,
+    pub slashed_amount_current: u64,
+    pub slashed_amount_cumulative: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct WithdrawUnstakedDepositsEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_pending_amount: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct HarvestEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub reward_index: u64,
+    pub reward_mint: Pubkey,
+    pub reward_user: u64,
+    pub reward_treasury: u64,
+    pub reward_user_transfer_fee: u64,
+    pub reward_treasury_transfer_fee: u64,
+    pub reward_vested: u64,
+    pub reward_per_share_scaled: u128,
+    pub rewards_issued_unclaimed: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct CompoundRewardEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub reward_index: u64,
+    pub reward_mint: Pubkey,
+    pub amount_compounded: u64,
+    pub reward_treasury: u64,
+    pub active_stake_scaled_after: u128,
+    pub pending_deposit_stake_scaled_after: u128,
+    pub total_staked_amount: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct RewardUserOnceEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub reward_index: u64,
+    pub amount: u64,
+    pub rewards_issued_unclaimed_before: u64,
+    pub rewards_issued_unclaimed_after: u64,
+}
+
+#[event]
+pub struct OwnershipTransferredEvent {
+    pub farm_state: Pubkey,
+    pub old_user_state: Pubkey,
+    pub new_user_state: Pubkey,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub amount: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct DepositToFarmVaultEvent {
+    pub farm_state: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub total_staked_amount: u64,
+}
+
+#[event]
+pub struct WithdrawFromFarmVaultEvent {
+    pub farm_state: Pubkey,
+    pub withdraw_authority: Pubkey,
+    pub requested_amount: u64,
+    pub amount: u64,
+    pub total_staked_amount: u64,
+    pub total_pending_amount: u64,
+    pub is_farm_frozen: bool,
+}
+
+#[event]
+pub struct WithdrawSlashedAmountEvent {
+    pub farm_state: Pubkey,
+    pub slashed_amount_spill_address: Pubkey,
+    pub amount: u64,
+    pub slashed_amount_cumulative: u64,
+}
+
+#[event]
+pub struct WithdrawTreasuryEvent {
+    pub global_config: Pubkey,
+    pub reward_mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub transfer_fee: u64,
+}
+
+#[event]
+pub struct StartFarmDecommissionEvent {
+    pub farm_state: Pubkey,
+    pub farm_admin: Pubkey,
+    pub ts: u64,
+}
+
+#[event]
+pub struct DecommissionFarmVaultEvent {
+    pub farm_state: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub transfer_fee: u64,
+}
+
+#[event]
+pub struct DecommissionFarmEvent {
+    pub farm_state: Pubkey,
+    pub farm_admin: Pubkey,
+    pub num_vaults_closed: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct RetireRewardEvent {
+    pub farm_state: Pubkey,
+    pub reward_index: u64,
+    pub reward_mint: Pubkey,
+    pub rewards_returned: u64,
+    pub vault_swept_amount: u64,
+    pub transfer_fee: u64,
+    pub retire_grace_end_ts: u64,
+    pub slot_freed: bool,
+    pub ts: u64,
+}
+
+#[event]
+pub struct UpdateFarmFrozenEvent {
+    pub farm_state: Pubkey,
+    pub farm_admin: Pubkey,
+    pub is_farm_frozen: bool,
+    pub ts: u64,
+}
+
+#[event]
+pub struct UpdatePauseFlagsEvent {
+    pub farm_state: Pubkey,
+    pub signer: Pubkey,
+    pub pause_flags_before: u64,
+    pub pause_flags_after: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct ConfigChangeQueuedEvent {
+    pub pending_config_change: Pubkey,
+    pub target: Pubkey,
+    pub target_kind: u8,
+    pub admin: Pubkey,
+    pub mode: u16,
+    pub data: Vec<u8>,
+    pub execute_after_ts: u64,
+}
+
+#[event]
+pub struct ConfigChangeExecutedEvent {
+    pub pending_config_change: Pubkey,
+    pub target: Pubkey,
+    pub target_kind: u8,
+    pub mode: u16,
+    pub data: Vec<u8>,
+    pub ts: u64,
+}
+
+#[event]
+pub struct ConfigChangeCancelledEvent {
+    pub pending_config_change: Pubkey,
+    pub target: Pubkey,
+    pub target_kind: u8,
+    pub mode: u16,
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct ClaimVestedEvent {
+    pub farm_state: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub reward_index: u64,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    pub transfer_fee: u64,
+    pub vesting_amount_after: u64,
+    pub vesting_duration_remaining: u32,
+    pub ts: u64,
+}
+
+#[event]
+pub struct CreateRewardDistributionEvent {
+    pub farm_state: Pubkey,
+    pub reward_distribution: Pubkey,
+    pub distribution_id: u64,
+    pub reward_index: u64,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub num_leaves: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct ClaimRewardDistributionEvent {
+    pub farm_state: Pubkey,
+    pub reward_distribution: Pubkey,
+    pub user_state: Pubkey,
+    pub owner: Pubkey,
+    pub reward_index: u64,
+    pub leaf_index: u64,
+    pub amount: u64,
+    pub rewards_issued_unclaimed_after: u64,
+}
+
+#[event]
+pub struct CloseRewardDistributionEvent {
+    pub farm_state: Pubkey,
+    pub reward_distribution: Pubkey,
+    pub reward_index: u64,
+    pub claimed_amount: u64,
+    pub returned_amount: u64,
+}
+
+#[event]
+pub struct MigrateStakeEvent {
+    pub source_farm_state: Pubkey,
+    pub source_user_state: Pubkey,
+    pub destination_farm_state: Pubkey,
+    pub destination_user_state: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub transfer_fee: u64,
+    pub amount_staked: u64,
+    pub ts: u64,
+}
+
+#[event]
+pub struct InitializeReceiptMintEvent {
+    pub farm_state: Pubkey,
+    pub receipt_mint: Pubkey,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct SyncReceiptEvent {
+    pub farm_state: Pubkey,
+    pub sender_user_state: Pubkey,
+    pub receiver_user_state: Pubkey,
+    pub amount: u64,
+    pub sender_receipt_amount_after: u64,
+    pub receiver_receipt_amount_after: u64,
+    pub ts: u64,
+}
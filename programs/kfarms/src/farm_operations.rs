@@ -11,16 +11,25 @@ pub use scope::{DatedPrice, OraclePrices};
 
 use crate::{
     dbg_msg, stake_operations as stake_ops,
-    state::{LockingMode, RewardPerTimeUnitPoint, RewardScheduleCurve, RewardType, TimeUnit},
+    state::{
+        ConfigChangeTarget, DepositLot, LockingMode, PauseFlag, RewardCurveMode,
+        RewardPerTimeUnitPoint, RewardScheduleCurve, RewardType, TimeUnit, WithdrawalTicket,
+    },
     types::{
-        AddRewardEffects, HarvestEffects, StakeEffects, WithdrawEffects, WithdrawRewardEffects,
+        AddRewardEffects, HarvestEffects, PendingRewardReturnData, RetireRewardEffects,
+        StakeEffects, TransferPositionEffects, UnstakeEffects, UserPendingRewardsReturnData,
+        WithdrawEffects, WithdrawRewardEffects,
     },
     utils::{
-        consts::{BPS_DIV_FACTOR, MAX_REWARDS_TOKENS},
-        math::{ten_pow, u64_mul_div},
+        consts::{
+            BPS_DIV_FACTOR, MAX_DEPOSIT_LOTS, MAX_DISTRIBUTION_LEAVES, MAX_REWARDS_TOKENS,
+            MAX_WITHDRAWAL_TICKETS, PENDING_CONFIG_CHANGE_DATA_LEN,
+        },
+        math::{full_decimal_mul_div, ten_pow, u64_mul_div},
+        merkle,
     },
-    xmsg, FarmConfigOption, FarmError, FarmState, GlobalConfig, GlobalConfigOption, RewardInfo,
-    UserState,
+    xmsg, FarmConfigOption, FarmError, FarmState, GlobalConfig, GlobalConfigOption,
+    PendingConfigChange, RewardDistribution, RewardInfo, UserState,
 };
 
 pub fn update_global_config(
@@ -52,10 +61,82 @@ pub fn update_global_config(
             );
             global_config.treasury_fee_bps = value;
         }
+        GlobalConfigOption::SetGuardian => {
+            let value: [u8; 32] = value[0..32].try_into().unwrap();
+            let pubkey = Pubkey::new_from_array(value);
+            xmsg!(
+                "Changing global_config guardian {} -> {:?}",
+                global_config.guardian,
+                pubkey
+            );
+            global_config.guardian = pubkey;
+        }
+        GlobalConfigOption::SetConfigTimelock => {
+            let value = u64::from_le_bytes(value[..8].try_into().unwrap());
+            xmsg!(
+                "Changing global_config config_timelock_seconds {} -> {:?}",
+                global_config.config_timelock_seconds,
+                value
+            );
+            global_config.config_timelock_seconds = value;
+        }
     }
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn queue_config_change(
+    pending_config_change: &mut PendingConfigChange,
+    target: Pubkey,
+    target_kind: ConfigChangeTarget,
+    admin: Pubkey,
+    mode: u16,
+    data: &[u8],
+    timelock_seconds: u64,
+    now: u64,
+) -> Result<()> {
+    xmsg!(
+        "farm_operations::queue_config_change target={} mode={} timelock_seconds={}",
+        target,
+        mode,
+        timelock_seconds
+    );
+    require!(
+        data.len() <= PENDING_CONFIG_CHANGE_DATA_LEN,
+        FarmError::InvalidConfigValue
+    );
+
+    pending_config_change.target = target;
+    pending_config_change.target_kind = target_kind as u8;
+    pending_config_change.admin = admin;
+    pending_config_change.mode = mode;
+    pending_config_change.data_len = data.len() as u16;
+    pending_config_change.data[..data.len()].copy_from_slice(data);
+    pending_config_change.queued_ts = now;
+    pending_config_change.execute_after_ts = now
+        .checked_add(timelock_seconds)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    Ok(())
+}
+
+pub fn check_config_change_executable(
+    pending_config_change: &PendingConfigChange,
+    now: u64,
+) -> Result<()> {
+    xmsg!(
+        "farm_operations::check_config_change_executable execute_after_ts={} now={}",
+        pending_config_change.execute_after_ts,
+        now
+    );
+    require!(
+        now >= pending_config_change.execute_after_ts,
+        FarmError::ConfigChangeTimelockNotElapsed
+    );
+
+    Ok(())
+}
+
 pub fn initialize_reward(
     farm_state: &mut FarmState,
     reward_vault: Pubkey,
@@ -63,12 +144,26 @@ pub fn initialize_reward(
     mint_decimals: u8,
     mint_token_program: Pubkey,
     ts: u64,
-) -> Result<()> {
-    if farm_state.num_reward_tokens == MAX_REWARDS_TOKENS as u64 {
-        return Err(FarmError::MaxRewardNumberReached.into());
-    }
+) -> Result<usize> {
+    let free_index = farm_state.reward_infos[..farm_state.num_reward_tokens as usize]
+        .iter()
+        .position(RewardInfo::is_free);
+
+    let reward_index = match free_index {
+        Some(reward_index) => reward_index,
+        None if farm_state.num_reward_tokens == MAX_REWARDS_TOKENS as u64 => {
+            return Err(FarmError::MaxRewardNumberReached.into());
+        }
+        None => {
+            farm_state.num_reward_tokens = farm_state
+                .num_reward_tokens
+                .checked_add(1)
+                .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+            farm_state.num_reward_tokens as usize - 1
+        }
+    };
 
-    let reward_info = &mut farm_state.reward_infos[farm_state.num_reward_tokens as usize];
+    let reward_info = &mut farm_state.reward_infos[reward_index];
 
     reward_info.rewards_available = 0;
     reward_info.rewards_vault = reward_vault;
@@ -79,12 +174,7 @@ pub fn initialize_reward(
 
     reward_info.last_issuance_ts = ts;
 
-    farm_state.num_reward_tokens = farm_state
-        .num_reward_tokens
-        .checked_add(1)
-        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
-
-    Ok(())
+    Ok(reward_index)
 }
 
 pub fn add_reward(
@@ -96,10 +186,19 @@ pub fn add_reward(
     ts: u64,
 ) -> Result<AddRewardEffects> {
     xmsg!("farm_operations::add_reward amount={}", amount);
+    require!(
+        !farm_state.is_paused(PauseFlag::AddRewards),
+        FarmError::OperationPaused
+    );
+    require!(
+        !farm_state.is_decommissioning(),
+        FarmError::FarmDecommissioning
+    );
     refresh_global_rewards(farm_state, scope_price, ts)?;
 
     let reward = &mut farm_state.reward_infos[reward_index];
     require!(reward.token.mint == mint, FarmError::RewardDoesNotExist);
+    require!(!reward.is_retiring(), FarmError::RewardRetiring);
 
     reward.rewards_available = reward
         .rewards_available
@@ -143,6 +242,85 @@ pub fn withdraw_reward(
     })
 }
 
+pub fn retire_reward(
+    farm_state: &mut FarmState,
+    scope_price: Option<DatedPrice>,
+    mint: &Pubkey,
+    reward_index: usize,
+    grace_period: u64,
+    ts: u64,
+) -> Result<RetireRewardEffects> {
+    xmsg!(
+        "farm_operations::retire_reward reward_index={} grace_period={}",
+        reward_index,
+        grace_period
+    );
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+
+    let reward = &mut farm_state.reward_infos[reward_index];
+    require!(
+        !reward.is_free() && reward.token.mint == *mint,
+        FarmError::RewardDoesNotExist
+    );
+
+    require!(
+        reward.rewards_distribution_reserved == 0,
+        FarmError::RewardHasActiveDistributions
+    );
+    require!(
+        reward.generation < u8::MAX,
+        FarmError::RewardSlotGenerationExhausted
+    );
+
+    let reward_amount = reward.rewards_available;
+    reward.rewards_available = 0;
+    reward.reward_schedule_curve = RewardScheduleCurve::default();
+    reward.reward_curve_mode = RewardCurveMode::Step as u8;
+
+    if reward.rewards_issued_unclaimed > 0 || reward.rewards_vesting > 0 {
+        if !reward.is_retiring() {
+            require!(grace_period > 0, FarmError::RewardHasUnclaimedRewards);
+            reward.retire_grace_end_ts = ts
+                .checked_add(grace_period)
+                .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+            xmsg!(
+                "farm_operations::retire_reward unclaimed={} vesting={} grace_end_ts={}",
+                reward.rewards_issued_unclaimed,
+                reward.rewards_vesting,
+                reward.retire_grace_end_ts
+            );
+            return Ok(RetireRewardEffects {
+                reward_amount,
+                slot_freed: false,
+            });
+        }
+        require!(
+            ts >= reward.retire_grace_end_ts,
+            FarmError::RewardRetireGracePeriodNotElapsed
+        );
+    }
+
+    xmsg!(
+        "farm_operations::retire_reward freeing slot, forfeited_unclaimed={} forfeited_vesting={}",
+        reward.rewards_issued_unclaimed,
+        reward.rewards_vesting
+    );
+    let generation = reward
+        .generation
+        .checked_add(1)
+        .ok_or_else(|| dbg_msg!(FarmError::RewardSlotGenerationExhausted))?;
+    *reward = RewardInfo {
+        generation,
+        last_issuance_ts: ts,
+        ..RewardInfo::default()
+    };
+
+    Ok(RetireRewardEffects {
+        reward_amount,
+        slot_freed: true,
+    })
+}
+
 pub fn update_farm_config(
     farm_state: &mut FarmState,
     scope_price: Option<DatedPrice>,
@@ -159,7 +337,10 @@ pub fn update_farm_config(
         | FarmConfigOption::UpdateRewardMinClaimDuration
         | FarmConfigOption::RewardType
         | FarmConfigOption::RpsDecimals
-        | FarmConfigOption::UpdateRewardScheduleCurvePoints => {
+        | FarmConfigOption::UpdateRewardScheduleCurvePoints
+        | FarmConfigOption::UpdateRewardVestingDuration
+        | FarmConfigOption::UpdateRewardCurveMode
+        | FarmConfigOption::UpdateRewardForfeitVestingOnUnstake => {
             let reward_index: u64 = BorshDeserialize::try_from_slice(&data[..8])?;
             require!(
                 reward_index < farm_state.num_reward_tokens,
@@ -196,6 +377,10 @@ pub fn update_farm_config(
                 xmsg!("farm_operations::update_farm_config ERROR: delegated farm cannot change deposit_warmup_period");
                 return err!(FarmError::FarmDelegated);
             }
+            if farm_state.has_receipt_mint() {
+                xmsg!("farm_operations::update_farm_config ERROR: receipt farm cannot change deposit_warmup_period");
+                return err!(FarmError::ReceiptMintRequiresNoWarmup);
+            }
             let value: u32 = BorshDeserialize::try_from_slice(data)?;
             xmsg!("farm_operations::update_farm_config deposit_warmup_period={value}",);
             xmsg!("prev value {:?}", farm_state.deposit_warmup_period);
@@ -327,6 +512,48 @@ pub fn update_farm_config(
             xmsg!("prev value {:?}", farm_state.is_harvesting_permissionless);
             farm_state.is_harvesting_permissionless = value as u8;
         }
+        FarmConfigOption::UpdateBoostMaxMultiplierBps => {
+            if farm_state.is_delegated() {
+                xmsg!("farm_operations::update_farm_config ERROR: delegated farm cannot enable boosts");
+                return err!(FarmError::FarmDelegated);
+            }
+            let value: u64 = BorshDeserialize::try_from_slice(data)?;
+            require!(
+                value == 0 || value >= BPS_DIV_FACTOR,
+                FarmError::InvalidConfigValue
+            );
+            xmsg!("farm_operations::update_farm_config boost_max_multiplier_bps={value}",);
+            xmsg!("prev value {:?}", farm_state.boost_max_multiplier_bps);
+            farm_state.boost_max_multiplier_bps = value;
+        }
+        FarmConfigOption::UpdateBoostMaxLockDuration => {
+            if farm_state.is_delegated() {
+                xmsg!("farm_operations::update_farm_config ERROR: delegated farm cannot enable boosts");
+                return err!(FarmError::FarmDelegated);
+            }
+            let value: u64 = BorshDeserialize::try_from_slice(data)?;
+            xmsg!("farm_operations::update_farm_config boost_max_lock_duration={value}",);
+            xmsg!("prev value {:?}", farm_state.boost_max_lock_duration);
+            farm_state.boost_max_lock_duration = value;
+        }
+        FarmConfigOption::UpdateFarmGuardian => {
+            let pubkey: Pubkey = BorshDeserialize::try_from_slice(data)?;
+            xmsg!("farm_operations::update_farm_config guardian={pubkey}",);
+            xmsg!("prev value {:?}", farm_state.guardian);
+            farm_state.guardian = pubkey;
+        }
+        FarmConfigOption::UpdateConfigTimelock => {
+            let value: u64 = BorshDeserialize::try_from_slice(data)?;
+            xmsg!("farm_operations::update_farm_config config_timelock_seconds={value}",);
+            xmsg!("prev value {:?}", farm_state.config_timelock_seconds);
+            farm_state.config_timelock_seconds = value;
+        }
+        FarmConfigOption::UpdateMigrationTarget => {
+            let pubkey: Pubkey = BorshDeserialize::try_from_slice(data)?;
+            xmsg!("farm_operations::update_farm_config migration_target={pubkey}",);
+            xmsg!("prev value {:?}", farm_state.migration_target);
+            farm_state.migration_target = pubkey;
+        }
     };
     Ok(())
 }
@@ -350,6 +577,23 @@ pub(crate) fn update_reward_config(
             xmsg!("prev value {}", reward_info.min_claim_duration_seconds);
             reward_info.min_claim_duration_seconds = value
         }
+        FarmConfigOption::UpdateRewardVestingDuration => {
+            let value: u64 = BorshDeserialize::try_from_slice(data)?;
+            if value > u64::from(u32::MAX) {
+                xmsg!("ERROR: vesting_duration must fit in u32");
+                return Err(FarmError::InvalidConfigValue.into());
+            }
+            xmsg!("farm_operations::update_farm_config reward_vesting_duration={value}",);
+            xmsg!("prev value {}", reward_info.vesting_duration);
+            reward_info.vesting_duration = value;
+        }
+        FarmConfigOption::UpdateRewardForfeitVestingOnUnstake => {
+            let value: u8 = BorshDeserialize::try_from_slice(&data[..1])?;
+            require!(value <= 1, FarmError::InvalidConfigValue);
+            xmsg!("farm_operations::update_farm_config reward_forfeit_vesting_on_unstake={value}",);
+            xmsg!("prev value {}", reward_info.forfeit_vesting_on_unstake);
+            reward_info.forfeit_vesting_on_unstake = value;
+        }
         FarmConfigOption::RewardType => {
             let value: u8 = BorshDeserialize::try_from_slice(&data[..1])?;
             xmsg!(
@@ -370,7 +614,20 @@ pub(crate) fn update_reward_config(
 
             xmsg!("Updating reward schedule curve with points={:?}", points);
             xmsg!("Prev value {:?}", reward_info.reward_schedule_curve.points);
-            reward_info.reward_schedule_curve = RewardScheduleCurve::from_points(&points).unwrap();
+            reward_info.reward_schedule_curve =
+                RewardScheduleCurve::from_points(&points, reward_info.reward_curve_mode())?;
+        }
+        FarmConfigOption::UpdateRewardCurveMode => {
+            let value: u8 = BorshDeserialize::try_from_slice(&data[..1])?;
+            let curve_mode = RewardCurveMode::try_from_primitive(value)
+                .map_err(|_| error!(FarmError::InvalidConfigValue))?;
+            xmsg!(
+                "farm_operations::update_farm_config reward_curve_mode={value} mode={:?}",
+                curve_mode
+            );
+            xmsg!("prev value {:?}", reward_info.reward_curve_mode);
+            reward_info.reward_schedule_curve.validate(curve_mode)?;
+            reward_info.reward_curve_mode = value;
         }
         _ => unimplemented!(),
     }
@@ -398,6 +655,13 @@ pub fn initialize_user(
     user_state.rewards_issued_cumulative = [0; MAX_REWARDS_TOKENS];
     user_state.active_stake_scaled = 0;
     user_state.last_claim_ts = [ts; MAX_REWARDS_TOKENS];
+    for (user_generation, reward_info) in user_state
+        .reward_generations
+        .iter_mut()
+        .zip(farm_state.reward_infos.iter())
+    {
+        *user_generation = reward_info.generation;
+    }
 
     if farm_state.is_delegated() {
         user_state.is_farm_delegated = true as u8;
@@ -412,6 +676,35 @@ pub fn initialize_user(
     Ok(())
 }
 
+pub fn close_user(farm_state: &mut FarmState, user_state: &UserState) -> Result<()> {
+    xmsg!(
+        "farm_operations::close_user user_id={} num_users={}",
+        user_state.user_id,
+        farm_state.num_users
+    );
+
+    require!(
+        user_state.active_stake_scaled == 0
+            && user_state.pending_deposit_stake_scaled == 0
+            && user_state.pending_withdrawal_unstake_scaled == 0
+            && user_state.boosted_stake_scaled == 0,
+        FarmError::UserStateHasStake
+    );
+    require!(
+        (0..MAX_REWARDS_TOKENS).all(|i| (user_state.rewards_issued_unclaimed[i] == 0
+            && user_state.vesting_amount[i] == 0)
+            || user_state.reward_generations[i] != farm_state.reward_infos[i].generation),
+        FarmError::UserStateHasUnclaimedRewards
+    );
+
+    farm_state.num_users = farm_state
+        .num_users
+        .checked_sub(1)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    Ok(())
+}
+
 pub fn initialize_reward_ts_if_needed(farm_state: &mut FarmState, current_ts: u64) {
     if farm_state.total_staked_amount == 0 {
         for reward_info in farm_state
@@ -438,6 +731,29 @@ pub fn stake(
         amount,
         current_ts,
         true,
+        true,
+    )
+}
+
+pub fn stake_for(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    scope_price: Option<DatedPrice>,
+    amount: u64,
+    current_ts: u64,
+) -> Result<StakeEffects> {
+    require!(
+        !farm_state.has_receipt_mint(),
+        FarmError::ReceiptMintNotSupported
+    );
+    stake_with_cap_check(
+        farm_state,
+        user_state,
+        scope_price,
+        amount,
+        current_ts,
+        true,
+        false,
     )
 }
 
@@ -448,10 +764,20 @@ pub fn stake_with_cap_check(
     amount: u64,
     current_ts: u64,
     check_deposit_cap: bool,
+    update_last_stake_ts: bool,
 ) -> Result<StakeEffects> {
     xmsg!("farm_operations::stake amount={}", amount);
+    require!(!farm_state.is_frozen(), FarmError::FarmFrozen);
+    require!(
+        !farm_state.is_paused(PauseFlag::Stake),
+        FarmError::OperationPaused
+    );
+    require!(
+        !farm_state.is_decommissioning(),
+        FarmError::FarmDecommissioning
+    );
     refresh_global_rewards(farm_state, scope_price, current_ts)?;
-    user_refresh_all_rewards(farm_state, user_state)?;
+    user_refresh_all_rewards(farm_state, user_state, current_ts)?;
    
     user_refresh_stake(farm_state, user_state, current_ts)?;
 
@@ -464,18 +790,17 @@ pub fn stake_with_cap_check(
 
     if user_state.pending_deposit_stake_scaled != 0 {
         xmsg!(
-            "farm_operations::stake BEFORE: pending_user_stake_scaled={}, pending_user_stake_ts={},\
-             pending stake will be extended",
+            "farm_operations::stake BEFORE: pending_user_stake_scaled={}, pending_user_stake_ts={}",
             user_state.pending_deposit_stake_scaled,
             user_state.pending_deposit_stake_ts
         );
     }
 
     if farm_state.deposit_warmup_period > 0 {
-       
-        user_state.pending_deposit_stake_ts = current_ts
+        let activation_ts = current_ts
             .checked_add(farm_state.deposit_warmup_period.into())
             .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        add_deposit_lot(user_state, amount, activation_ts)?;
         let stake_gained = stake_ops::add_pending_deposit_stake(user_state, farm_state, amount)?;
         xmsg!(
             "farm_operations::stake AFTER: pending_user_stake_ts={},\
@@ -494,13 +819,77 @@ pub fn stake_with_cap_check(
         update_user_rewards_tally_on_stake_increase(farm_state, user_state, stake_gained)?;
     };
 
-    user_state.last_stake_ts = current_ts;
+    if update_last_stake_ts {
+        user_state.last_stake_ts = current_ts;
+    }
+
+    refresh_user_boost(farm_state, user_state, current_ts)?;
 
     Ok(StakeEffects {
         amount_to_stake: amount,
     })
 }
 
+pub fn compound(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    scope_price: Option<DatedPrice>,
+    amount: u64,
+    current_ts: u64,
+) -> Result<StakeEffects> {
+    xmsg!("farm_operations::compound amount={}", amount);
+    require!(
+        !farm_state.has_receipt_mint(),
+        FarmError::ReceiptMintNotSupported
+    );
+    stake_with_cap_check(
+        farm_state,
+        user_state,
+        scope_price,
+        amount,
+        current_ts,
+        true,
+        false,
+    )
+}
+
+pub fn stake_locked(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    scope_price: Option<DatedPrice>,
+    amount: u64,
+    lock_duration: u64,
+    current_ts: u64,
+) -> Result<StakeEffects> {
+    xmsg!(
+        "farm_operations::stake_locked amount={} lock_duration={}",
+        amount,
+        lock_duration
+    );
+    if lock_duration > 0 {
+        require!(farm_state.is_boost_enabled(), FarmError::BoostNotEnabled);
+        require!(
+            lock_duration <= farm_state.boost_max_lock_duration,
+            FarmError::InvalidLockDuration
+        );
+    }
+
+    let effects = stake(farm_state, user_state, scope_price, amount, current_ts)?;
+
+    if lock_duration > 0 {
+        let lock_end_ts = current_ts
+            .checked_add(lock_duration)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        user_state.lock_end_ts = cmp::max(user_state.lock_end_ts, lock_end_ts);
+        xmsg!(
+            "farm_operations::stake_locked lock_end_ts={}",
+            user_state.lock_end_ts
+        );
+        refresh_user_boost(farm_state, user_state, current_ts)?;
+    }
+
+    Ok(effects)
+}
 
 pub fn set_stake(
     farm_state: &mut FarmState,
@@ -508,6 +897,8 @@ pub fn set_stake(
     new_stake: u64,
     ts: u64,
 ) -> Result<()> {
+    require!(!farm_state.is_frozen(), FarmError::FarmFrozen);
+
    
    
    
@@ -532,7 +923,7 @@ pub fn set_stake(
 
    
     refresh_global_rewards(farm_state, None, ts)?;
-    user_refresh_all_rewards(farm_state, user_state)?;
+    user_refresh_all_rewards(farm_state, user_state, ts)?;
 
     type OpAssignU64 = dyn Fn(&mut u64, u64);
     type OpAssignU128 = dyn Fn(&mut u128, u128);
@@ -541,12 +932,24 @@ pub fn set_stake(
         if current_stake_amount > new_stake {
            
            
+            require!(
+                !farm_state.is_paused(PauseFlag::Unstake),
+                FarmError::OperationPaused
+            );
             let diff = current_stake_amount - new_stake;
 
             (diff, &u64::sub_assign, &u128::sub_assign)
         } else {
            
            
+            require!(
+                !farm_state.is_paused(PauseFlag::Stake),
+                FarmError::OperationPaused
+            );
+            require!(
+                !farm_state.is_decommissioning(),
+                FarmError::FarmDecommissioning
+            );
             let diff = new_stake - current_stake_amount;
             initialize_reward_ts_if_needed(farm_state, ts);
             user_state.last_stake_ts = ts;
@@ -584,20 +987,154 @@ pub fn harvest(
     ts: u64,
 ) -> Result<HarvestEffects> {
     xmsg!("farm_operations::harvest reward_index={}", reward_index);
+    require!(
+        !farm_state.is_paused(PauseFlag::Harvest),
+        FarmError::OperationPaused
+    );
     refresh_global_rewards(farm_state, scope_price, ts)?;
-    user_refresh_reward(farm_state, user_state, reward_index)?;
+    user_refresh_all_rewards(farm_state, user_state, ts)?;
 
-    let reward = user_state.rewards_issued_unclaimed[reward_index];
     require!(
-        ts.checked_sub(user_state.last_claim_ts[reward_index])
-            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?
-            >= farm_state.reward_infos[reward_index].min_claim_duration_seconds,
+        is_min_claim_duration_elapsed(farm_state, user_state, reward_index, ts)?,
         FarmError::MinClaimDurationNotReached
     );
+
+    claim_refreshed_reward(farm_state, user_state, global_config, reward_index, ts)
+}
+
+pub fn harvest_all(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    global_config: &GlobalConfig,
+    scope_price: Option<DatedPrice>,
+    ts: u64,
+) -> Result<Vec<Option<HarvestEffects>>> {
+    xmsg!("farm_operations::harvest_all");
+    require!(
+        !farm_state.is_paused(PauseFlag::Harvest),
+        FarmError::OperationPaused
+    );
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+    user_refresh_all_rewards(farm_state, user_state, ts)?;
+
+    let mut effects = Vec::with_capacity(farm_state.num_reward_tokens as usize);
+    for reward_index in 0..farm_state.num_reward_tokens as usize {
+        if !is_min_claim_duration_elapsed(farm_state, user_state, reward_index, ts)? {
+            xmsg!(
+                "farm_operations::harvest_all skipping reward_index={}, min claim duration not reached",
+                reward_index
+            );
+            effects.push(None);
+            continue;
+        }
+
+        effects.push(Some(claim_refreshed_reward(
+            farm_state,
+            user_state,
+            global_config,
+            reward_index,
+            ts,
+        )?));
+    }
+
+    Ok(effects)
+}
+
+pub fn get_user_pending_rewards(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    global_config: &GlobalConfig,
+    scope_price: Option<DatedPrice>,
+    ts: u64,
+) -> Result<UserPendingRewardsReturnData> {
+    xmsg!("farm_operations::get_user_pending_rewards ts={}", ts);
+
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+    user_refresh_all_rewards(farm_state, user_state, ts)?;
+    user_refresh_stake(farm_state, user_state, ts)?;
+
+    let mut pending_rewards = UserPendingRewardsReturnData {
+        ts,
+        ..Default::default()
+    };
+
+    for reward_index in 0..farm_state.num_reward_tokens as usize {
+        if farm_state.reward_infos[reward_index].is_free() {
+            continue;
+        }
+
+        let time_until_claimable = user_state.last_claim_ts[reward_index]
+            .saturating_add(farm_state.reward_infos[reward_index].min_claim_duration_seconds)
+            .saturating_sub(ts);
+        let HarvestEffects {
+            reward_user,
+            reward_treasury,
+            reward_vested,
+        } = claim_refreshed_reward(farm_state, user_state, global_config, reward_index, ts)?;
+
+        pending_rewards.rewards[reward_index] = PendingRewardReturnData {
+            claimable_user: reward_user,
+            claimable_now: if time_until_claimable == 0 {
+                reward_user
+            } else {
+                0
+            },
+            treasury_fee: reward_treasury,
+            newly_vested: reward_vested,
+            vesting_amount: user_state.vesting_amount[reward_index],
+            time_until_claimable,
+        };
+    }
+
+    pending_rewards.active_stake_scaled = user_state.active_stake_scaled;
+    let lock_end_ts = effective_lock_end_ts(farm_state, user_state, ts);
+    pending_rewards.locked_stake_scaled = if lock_end_ts > ts {
+        user_state.active_stake_scaled
+    } else {
+        0
+    };
+    pending_rewards.lock_end_ts = lock_end_ts;
+    pending_rewards.pending_deposit_stake_scaled = user_state.pending_deposit_stake_scaled;
+    pending_rewards.pending_deposit_stake_ts = user_state.pending_deposit_stake_ts;
+    pending_rewards.pending_withdrawal_unstake_scaled =
+        user_state.pending_withdrawal_unstake_scaled;
+    pending_rewards.pending_withdrawal_unstake_ts = user_state.pending_withdrawal_unstake_ts;
+
+    migrate_legacy_deposit_lot(farm_state, user_state);
+    migrate_legacy_withdrawal_ticket(farm_state, user_state);
+    pending_rewards.pending_deposit_lots = user_state.pending_deposit_lots;
+    pending_rewards.pending_withdrawal_tickets = user_state.pending_withdrawal_tickets;
+
+    Ok(pending_rewards)
+}
+
+fn is_min_claim_duration_elapsed(
+    farm_state: &FarmState,
+    user_state: &UserState,
+    reward_index: usize,
+    ts: u64,
+) -> Result<bool> {
+    Ok(ts
+        .checked_sub(user_state.last_claim_ts[reward_index])
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?
+        >= farm_state.reward_infos[reward_index].min_claim_duration_seconds)
+}
+
+fn claim_refreshed_reward(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    global_config: &GlobalConfig,
+    reward_index: usize,
+    ts: u64,
+) -> Result<HarvestEffects> {
+    let reward_released = release_vested_reward(farm_state, user_state, reward_index, ts)?;
+
+    let reward = user_state.rewards_issued_unclaimed[reward_index];
     if reward == 0 {
         return Ok(HarvestEffects {
             reward_treasury: 0,
-            reward_user: 0,
+            reward_user: reward_released,
+            reward_vested: 0,
         });
     }
 
@@ -616,17 +1153,184 @@ pub fn harvest(
         .checked_sub(reward_treasury)
         .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
 
+    let reward_vested = if farm_state.reward_infos[reward_index].vesting_duration > 0 {
+        add_vesting_reward(farm_state, user_state, reward_index, reward_user)?;
+        reward_user
+    } else {
+        0
+    };
+
     Ok(HarvestEffects {
-        reward_user,
+        reward_user: reward_user - reward_vested + reward_released,
         reward_treasury,
+        reward_vested,
     })
 }
 
+pub fn claim_vested(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    reward_index: usize,
+    ts: u64,
+) -> Result<u64> {
+    xmsg!(
+        "farm_operations::claim_vested reward_index={}",
+        reward_index
+    );
+    require!(
+        !farm_state.is_paused(PauseFlag::Harvest),
+        FarmError::OperationPaused
+    );
+    user_sync_reward_generation(farm_state, user_state, reward_index);
+
+    require!(
+        user_state.vesting_amount[reward_index] > 0,
+        FarmError::NothingToClaimVested
+    );
+    require!(
+        is_min_claim_duration_elapsed(farm_state, user_state, reward_index, ts)?,
+        FarmError::MinClaimDurationNotReached
+    );
+
+    release_vested_reward(farm_state, user_state, reward_index, ts)
+}
+
+fn release_vested_reward(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    reward_index: usize,
+    ts: u64,
+) -> Result<u64> {
+    let vesting_amount = user_state.vesting_amount[reward_index];
+    if vesting_amount == 0 {
+        return Ok(0);
+    }
+
+    let elapsed = ts.saturating_sub(user_state.last_claim_ts[reward_index]);
+    let duration_remaining = u64::from(user_state.vesting_duration_remaining[reward_index]);
+    let released = releasable_vested_reward(user_state, reward_index, ts);
+
+    user_state.vesting_amount[reward_index] = vesting_amount - released;
+    user_state.vesting_duration_remaining[reward_index] =
+        if user_state.vesting_amount[reward_index] == 0 {
+            0
+        } else {
+            (duration_remaining - elapsed) as u32
+        };
+    user_state.last_claim_ts[reward_index] = ts;
+
+    farm_state.reward_infos[reward_index].rewards_vesting = farm_state.reward_infos[reward_index]
+        .rewards_vesting
+        .checked_sub(released)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    xmsg!(
+        "farm_operations::release_vested_reward reward_index={} released={} vesting_amount={} duration_remaining={}",
+        reward_index,
+        released,
+        user_state.vesting_amount[reward_index],
+        user_state.vesting_duration_remaining[reward_index]
+    );
+
+    Ok(released)
+}
+
+fn releasable_vested_reward(user_state: &UserState, reward_index: usize, ts: u64) -> u64 {
+    let vesting_amount = user_state.vesting_amount[reward_index];
+    let elapsed = ts.saturating_sub(user_state.last_claim_ts[reward_index]);
+    let duration_remaining = u64::from(user_state.vesting_duration_remaining[reward_index]);
+    if elapsed >= duration_remaining {
+        vesting_amount
+    } else {
+        u64_mul_div(vesting_amount, elapsed, duration_remaining)
+    }
+}
+
+fn forfeit_unvested_rewards(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    ts: u64,
+) -> Result<()> {
+    for reward_index in 0..farm_state.num_reward_tokens as usize {
+        if farm_state.reward_infos[reward_index].forfeit_vesting_on_unstake == 0
+            || user_state.vesting_amount[reward_index] == 0
+        {
+            continue;
+        }
+
+        let releasable = releasable_vested_reward(user_state, reward_index, ts);
+        let forfeited = user_state.vesting_amount[reward_index] - releasable;
+
+        user_state.vesting_amount[reward_index] = releasable;
+        user_state.vesting_duration_remaining[reward_index] = 0;
+
+        let reward_info = &mut farm_state.reward_infos[reward_index];
+        reward_info.rewards_vesting = reward_info
+            .rewards_vesting
+            .checked_sub(forfeited)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        reward_info.rewards_available = reward_info
+            .rewards_available
+            .checked_add(forfeited)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+        xmsg!(
+            "farm_operations::forfeit_unvested_rewards reward_index={} forfeited={} still_claimable={}",
+            reward_index,
+            forfeited,
+            releasable
+        );
+    }
+
+    Ok(())
+}
+
+fn add_vesting_reward(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    reward_index: usize,
+    amount: u64,
+) -> Result<()> {
+    let vesting_duration = farm_state.reward_infos[reward_index].vesting_duration;
+    let vesting_amount = user_state.vesting_amount[reward_index];
+    let duration_remaining = user_state.vesting_duration_remaining[reward_index];
+
+    let new_vesting_amount = vesting_amount
+        .checked_add(amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    let new_duration_remaining = (u128::from(vesting_amount) * u128::from(duration_remaining)
+        + u128::from(amount) * u128::from(vesting_duration))
+        / u128::from(new_vesting_amount);
+
+    user_state.vesting_amount[reward_index] = new_vesting_amount;
+    user_state.vesting_duration_remaining[reward_index] = new_duration_remaining
+        .try_into()
+        .map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    farm_state.reward_infos[reward_index].rewards_vesting = farm_state.reward_infos[reward_index]
+        .rewards_vesting
+        .checked_add(amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    xmsg!(
+        "farm_operations::add_vesting_reward reward_index={} amount={} vesting_amount={} duration_remaining={}",
+        reward_index,
+        amount,
+        new_vesting_amount,
+        user_state.vesting_duration_remaining[reward_index]
+    );
+
+    Ok(())
+}
+
 pub fn user_refresh_reward(
     farm_state: &mut FarmState,
     user_state: &mut UserState,
     reward_index: usize,
+    ts: u64,
 ) -> Result<()> {
+    user_sync_reward_generation(farm_state, user_state, reward_index);
+
     xmsg!(
         "farm_operations::user_refresh_reward reward_index {} Global stake {} User stake {} prev_reward_tally {} rpt {}",
         reward_index,
@@ -643,7 +1347,8 @@ pub fn user_refresh_reward(
        
         reward_per_share * user_state.active_stake_scaled
     } else {
-        reward_per_share * user_state.get_active_stake_decimal()
+        reward_per_share
+            * (user_state.get_active_stake_decimal() + user_state.get_boosted_stake_decimal())
     };
 
     let reward: u64 = (new_reward_tally - rewards_tally)
@@ -653,9 +1358,27 @@ pub fn user_refresh_reward(
    
     let new_reward_tally = rewards_tally + reward.into();
 
+    let forfeited = boost_decay_forfeited_reward(farm_state, user_state, reward, ts)?;
+    if forfeited > 0 {
+        let reward_info = &mut farm_state.reward_infos[reward_index];
+        reward_info.rewards_issued_unclaimed = reward_info
+            .rewards_issued_unclaimed
+            .checked_sub(forfeited)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        reward_info.rewards_issued_cumulative = reward_info
+            .rewards_issued_cumulative
+            .saturating_sub(forfeited);
+        reward_info.rewards_available = reward_info
+            .rewards_available
+            .checked_add(forfeited)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    }
+    let reward = reward - forfeited;
+
     xmsg!(
-        "farm_operations::user_refresh_reward reward {}, new_reward_tally (scaled) {}",
+        "farm_operations::user_refresh_reward reward {}, boost_forfeited {}, new_reward_tally (scaled) {}",
         reward,
+        forfeited,
         new_reward_tally.to_scaled_val::<u128>().unwrap()
     );
 
@@ -671,17 +1394,47 @@ pub fn user_refresh_reward(
 pub fn user_refresh_all_rewards(
     farm_state: &mut FarmState,
     user_state: &mut UserState,
+    ts: u64,
 ) -> Result<()> {
+    for reward_index in 0..farm_state.num_reward_tokens as usize {
+        user_sync_reward_generation(farm_state, user_state, reward_index);
+    }
+
     if user_state.active_stake_scaled > 0 {
         for reward_index in 0..farm_state.num_reward_tokens as usize {
-            user_refresh_reward(farm_state, user_state, reward_index)?;
+            user_refresh_reward(farm_state, user_state, reward_index, ts)?;
         }
     }
+
+    refresh_user_boost(farm_state, user_state, ts)?;
    
 
     Ok(())
 }
 
+fn user_sync_reward_generation(
+    farm_state: &FarmState,
+    user_state: &mut UserState,
+    reward_index: usize,
+) {
+    let generation = farm_state.reward_infos[reward_index].generation;
+    if user_state.reward_generations[reward_index] == generation {
+        return;
+    }
+
+    xmsg!(
+        "farm_operations::user_sync_reward_generation reward_index={} forfeited_unclaimed={}",
+        reward_index,
+        user_state.rewards_issued_unclaimed[reward_index]
+    );
+    user_state.reward_generations[reward_index] = generation;
+    user_state.rewards_tally_scaled[reward_index] = 0;
+    user_state.rewards_issued_unclaimed[reward_index] = 0;
+    user_state.rewards_issued_cumulative[reward_index] = 0;
+    user_state.vesting_amount[reward_index] = 0;
+    user_state.vesting_duration_remaining[reward_index] = 0;
+}
+
 fn user_refresh_stake(
     farm_state: &mut FarmState,
     user_state: &mut UserState,
@@ -689,11 +1442,51 @@ fn user_refresh_stake(
 ) -> Result<()> {
     initialize_reward_ts_if_needed(farm_state, current_ts);
 
-    if user_state.pending_deposit_stake_scaled > 0
-        && current_ts >= user_state.pending_deposit_stake_ts
-    {
+    if user_state.pending_deposit_stake_scaled == 0 {
+        return Ok(());
+    }
+
+    migrate_legacy_deposit_lot(farm_state, user_state);
+
+    let mut activated_weight: u64 = 0;
+    let mut total_weight: u64 = 0;
+    for lot in user_state.pending_deposit_lots.iter() {
+        if lot.activation_ts <= current_ts {
+            activated_weight = activated_weight
+                .checked_add(lot.amount)
+                .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        }
+        total_weight = total_weight
+            .checked_add(lot.amount)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    }
+
+    if activated_weight > 0 {
+        let pending_deposit_stake = user_state.get_pending_deposit_stake_decimal();
+        let stake_to_activate = if activated_weight == total_weight {
+            pending_deposit_stake
+        } else {
+            full_decimal_mul_div(
+                pending_deposit_stake,
+                activated_weight,
+                Decimal::from(total_weight),
+            )
+        };
+
         let (amount_staked, active_stake_gained) =
-            stake_ops::activate_pending_stake(user_state, farm_state)?;
+            stake_ops::activate_pending_stake(user_state, farm_state, stake_to_activate)?;
+
+        let mut remaining_lots = [DepositLot::default(); MAX_DEPOSIT_LOTS];
+        for (remaining_lot, lot) in remaining_lots.iter_mut().zip(
+            user_state
+                .pending_deposit_lots
+                .iter()
+                .filter(|lot| !lot.is_empty() && lot.activation_ts > current_ts),
+        ) {
+            *remaining_lot = *lot;
+        }
+        user_state.pending_deposit_lots = remaining_lots;
+
         xmsg!(
             "farm_operations::user_refresh_stake amount_staked {} active_stake_gained (scaled) {}",
             amount_staked,
@@ -714,7 +1507,7 @@ pub fn user_refresh_state(
     current_ts: u64,
 ) -> Result<()> {
     refresh_global_rewards(farm_state, scope_price, current_ts)?;
-    user_refresh_all_rewards(farm_state, user_state)?;
+    user_refresh_all_rewards(farm_state, user_state, current_ts)?;
 
     let is_delegated = farm_state.is_delegated();
 
@@ -724,11 +1517,165 @@ pub fn user_refresh_state(
     if !farm_state.is_delegated() {
        
         user_refresh_stake(farm_state, user_state, current_ts)?;
+        refresh_user_boost(farm_state, user_state, current_ts)?;
     }
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn create_reward_distribution(
+    farm_state: &mut FarmState,
+    reward_distribution: &mut RewardDistribution,
+    scope_price: Option<DatedPrice>,
+    farm_state_key: Pubkey,
+    claimed_bitmap_key: Pubkey,
+    distribution_id: u64,
+    reward_index: usize,
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    num_leaves: u64,
+    ts: u64,
+) -> Result<()> {
+    xmsg!(
+        "farm_operations::create_reward_distribution reward_index={} total_amount={} num_leaves={}",
+        reward_index,
+        total_amount,
+        num_leaves
+    );
+    require!(total_amount > 0, FarmError::DistributionInvalidAmount);
+    require!(
+        num_leaves > 0 && num_leaves <= MAX_DISTRIBUTION_LEAVES,
+        FarmError::DistributionTooManyLeaves
+    );
+
+    require!(
+        !farm_state.is_decommissioning(),
+        FarmError::FarmDecommissioning
+    );
+
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+
+    farm_state.num_reward_distributions = farm_state
+        .num_reward_distributions
+        .checked_add(1)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    let reward = &mut farm_state.reward_infos[reward_index];
+    require!(!reward.is_free(), FarmError::RewardDoesNotExist);
+    require!(!reward.is_retiring(), FarmError::RewardRetiring);
+    require!(
+        reward.rewards_available >= total_amount,
+        FarmError::DistributionInsufficientRewards
+    );
+
+    reward.rewards_available -= total_amount;
+    reward.rewards_distribution_reserved = reward
+        .rewards_distribution_reserved
+        .checked_add(total_amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    reward_distribution.farm_state = farm_state_key;
+    reward_distribution.claimed_bitmap = claimed_bitmap_key;
+    reward_distribution.merkle_root = merkle_root;
+    reward_distribution.distribution_id = distribution_id;
+    reward_distribution.reward_index = reward_index as u64;
+    reward_distribution.total_amount = total_amount;
+    reward_distribution.num_leaves = num_leaves;
+    reward_distribution.created_ts = ts;
+    reward_distribution.reward_generation = reward.generation;
+
+    Ok(())
+}
+
+pub fn claim_reward_distribution(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    reward_distribution: &mut RewardDistribution,
+    claimed_bitmap: &mut [u8],
+    leaf_index: u64,
+    amount: u64,
+    proof: &[[u8; 32]],
+) -> Result<()> {
+    xmsg!(
+        "farm_operations::claim_reward_distribution leaf_index={} amount={}",
+        leaf_index,
+        amount
+    );
+    let reward_index = reward_distribution.reward_index as usize;
+    require!(
+        farm_state.reward_infos[reward_index].generation == reward_distribution.reward_generation,
+        FarmError::DistributionRewardMismatch
+    );
+    require!(
+        leaf_index < reward_distribution.num_leaves,
+        FarmError::DistributionLeafIndexOutOfRange
+    );
+    require!(
+        amount > 0 && amount <= reward_distribution.remaining_amount(),
+        FarmError::DistributionInvalidAmount
+    );
+
+    let leaf = merkle::distribution_leaf(leaf_index, &user_state.owner, amount);
+    require!(
+        merkle::verify_proof(proof, &reward_distribution.merkle_root, leaf),
+        FarmError::DistributionInvalidProof
+    );
+
+    let byte = &mut claimed_bitmap[(leaf_index / 8) as usize];
+    let mask = 1u8 << (leaf_index % 8);
+    require!(*byte & mask == 0, FarmError::DistributionLeafAlreadyClaimed);
+    *byte |= mask;
+
+    reward_distribution.claimed_amount += amount;
+    reward_distribution.num_claimed += 1;
+
+    user_sync_reward_generation(farm_state, user_state, reward_index);
+
+    let reward = &mut farm_state.reward_infos[reward_index];
+    reward.rewards_distribution_reserved -= amount;
+    reward.rewards_issued_unclaimed = reward
+        .rewards_issued_unclaimed
+        .checked_add(amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    reward.rewards_issued_cumulative = reward.rewards_issued_cumulative.saturating_add(amount);
+
+    user_state.rewards_issued_unclaimed[reward_index] = user_state.rewards_issued_unclaimed
+        [reward_index]
+        .checked_add(amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    user_state.rewards_issued_cumulative[reward_index] =
+        user_state.rewards_issued_cumulative[reward_index].saturating_add(amount);
+
+    Ok(())
+}
+
+pub fn close_reward_distribution(
+    farm_state: &mut FarmState,
+    reward_distribution: &RewardDistribution,
+) -> Result<u64> {
+    let remaining_amount = reward_distribution.remaining_amount();
+    xmsg!(
+        "farm_operations::close_reward_distribution remaining_amount={}",
+        remaining_amount
+    );
+
+    farm_state.num_reward_distributions = farm_state.num_reward_distributions.saturating_sub(1);
+
+    let reward = &mut farm_state.reward_infos[reward_distribution.reward_index as usize];
+    if reward.generation != reward_distribution.reward_generation {
+        return Ok(0);
+    }
+
+    reward.rewards_distribution_reserved -= remaining_amount;
+    reward.rewards_available = reward
+        .rewards_available
+        .checked_add(remaining_amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    Ok(remaining_amount)
+}
+
 pub fn reward_user_once(
     farm_state: &mut FarmState,
     user_state: &mut UserState,
@@ -752,15 +1699,22 @@ pub fn unstake(
     scope_price: Option<DatedPrice>,
     requested_stake_withdrawal: Decimal,
     ts: u64,
-) -> Result<()> {
+) -> Result<UnstakeEffects> {
     xmsg!(
         "farm_operations::unstake amount of stake={}",
         requested_stake_withdrawal
     );
+    require!(
+        !farm_state.is_paused(PauseFlag::Unstake),
+        FarmError::OperationPaused
+    );
 
     refresh_global_rewards(farm_state, scope_price, ts)?;
 
-    user_refresh_all_rewards(farm_state, user_state)?;
+    user_refresh_all_rewards(farm_state, user_state, ts)?;
+    refresh_user_boost(farm_state, user_state, ts)?;
+
+    require!(user_state.lock_end_ts <= ts, FarmError::UserStakeLocked);
 
     let stake_share_to_unstake = std::cmp::min(
         requested_stake_withdrawal,
@@ -774,28 +1728,19 @@ pub fn unstake(
    
    
 
-   
-    if user_state.pending_withdrawal_unstake_scaled > 0 {
-        if user_state.pending_withdrawal_unstake_ts <= ts {
-            xmsg!("farm_operations::unstake pending withdrawal elapsed already exist but not withdrawn yet");
-            return err!(FarmError::PendingWithdrawalNotWithdrawnYet);
-        }
-        xmsg!(
-            "farm_operations::unstake pending withdrawal already exist and will be extended.\
-                already pending withdrawal stake={}, added={}, old ts={}",
-            user_state.get_pending_withdrawal_unstake_decimal(),
-            stake_share_to_unstake,
-            user_state.pending_withdrawal_unstake_ts
-        );
-    }
+    migrate_legacy_withdrawal_ticket(farm_state, user_state);
 
-    user_state.pending_withdrawal_unstake_ts = ts
+    let maturity_ts = ts
         .checked_add(farm_state.withdrawal_cooldown_period.into())
         .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
 
     let (token_amount_removed, added_pending_withdrawal_unstake, token_amount_penalty) =
         stake_ops::unstake(user_state, farm_state, stake_share_to_unstake, ts)?;
 
+    if token_amount_removed > 0 {
+        add_withdrawal_ticket(user_state, token_amount_removed, maturity_ts, ts)?;
+    }
+
     xmsg!(
         "farm_operations::unstake added_pending_withdrawal_unstake={}, token_amount_unstaked={}",
         added_pending_withdrawal_unstake,
@@ -806,28 +1751,473 @@ pub fn unstake(
     farm_state.slashed_amount_current += token_amount_penalty;
     farm_state.slashed_amount_cumulative += token_amount_penalty;
 
-    for i in 0..farm_state.num_reward_tokens as usize {
-        let reward_tally = &mut user_state.rewards_tally_scaled[i];
-        let reward_info = &farm_state.reward_infos[i];
+    update_user_rewards_tally_on_stake_decrease(farm_state, user_state, stake_share_to_unstake)?;
 
-        let reward_tally_decimal = Decimal::from_scaled_val(*reward_tally);
-        let tally_loss = stake_share_to_unstake * reward_info.get_reward_per_share_decimal();
+    if user_state.active_stake_scaled == 0 {
+        forfeit_unvested_rewards(farm_state, user_state, ts)?;
+    }
 
-       
-       
-        require_gt!(
-            reward_tally_decimal + Decimal::one(),
-            tally_loss,
-            FarmError::IntegerOverflow
+    Ok(UnstakeEffects {
+        stake_shares_unstaked_scaled: stake_share_to_unstake
+            .to_scaled_val()
+            .map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?,
+        amount_unstaked: token_amount_removed,
+        penalty_amount: token_amount_penalty,
+    })
+}
+
+pub fn migrate_stake_out(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    scope_price: Option<DatedPrice>,
+    destination_farm: &Pubkey,
+    ts: u64,
+) -> Result<u64> {
+    xmsg!(
+        "farm_operations::migrate_stake_out destination_farm={}",
+        destination_farm
+    );
+    require!(
+        farm_state.migration_target != Pubkey::default()
+            && farm_state.migration_target == *destination_farm,
+        FarmError::MigrationTargetNotAllowed
+    );
+    require!(!farm_state.is_delegated(), FarmError::FarmDelegated);
+    require!(
+        !farm_state.is_paused(PauseFlag::Unstake),
+        FarmError::OperationPaused
+    );
+
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+
+    user_refresh_all_rewards(farm_state, user_state, ts)?;
+    refresh_user_boost(farm_state, user_state, ts)?;
+
+    require!(user_state.lock_end_ts <= ts, FarmError::UserStakeLocked);
+
+    let locking_start_ts = match farm_state.get_locking_mode() {
+        LockingMode::None => None,
+        LockingMode::WithExpiry => Some(farm_state.locking_start_timestamp),
+        LockingMode::Continuous => Some(user_state.last_stake_ts),
+    };
+    if let Some(locking_start_ts) = locking_start_ts {
+        require!(
+            ts < locking_start_ts
+                || ts >= locking_start_ts.saturating_add(farm_state.locking_duration),
+            FarmError::UserStakeLocked
         );
-        let reward_tally_scaled: u128 = reward_tally_decimal.to_scaled_val().unwrap();
-        let tally_loss_scaled: u128 = tally_loss.to_scaled_val().unwrap();
-        let new_reward_tally_decimal_scaled = reward_tally_scaled.saturating_sub(tally_loss_scaled);
+    }
+
+    let stake_shares = user_state.get_active_stake_decimal();
+    require!(stake_shares > Decimal::zero(), FarmError::NothingToUnstake);
+
+    let amount = stake_ops::remove_active_stake(user_state, farm_state, stake_shares)?;
+    update_user_rewards_tally_on_stake_decrease(farm_state, user_state, stake_shares)?;
+    check_receipt_backing(user_state)?;
+    forfeit_unvested_rewards(farm_state, user_state, ts)?;
+
+    xmsg!(
+        "farm_operations::migrate_stake_out stake_shares={} amount={}",
+        stake_shares,
+        amount
+    );
+
+    Ok(amount)
+}
+
+pub fn transfer_position(
+    farm_state: &mut FarmState,
+    old_user_state: &mut UserState,
+    new_user_state: &mut UserState,
+    scope_price: Option<DatedPrice>,
+    requested_amount: u64,
+    ts: u64,
+) -> Result<TransferPositionEffects> {
+    xmsg!(
+        "farm_operations::transfer_position requested_amount={}",
+        requested_amount
+    );
+    require!(!farm_state.is_frozen(), FarmError::FarmFrozen);
+    require!(
+        !farm_state.is_paused(PauseFlag::Stake) && !farm_state.is_paused(PauseFlag::Unstake),
+        FarmError::OperationPaused
+    );
+
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+
+    user_refresh_all_rewards(farm_state, old_user_state, ts)?;
+    user_refresh_stake(farm_state, old_user_state, ts)?;
+    user_refresh_all_rewards(farm_state, new_user_state, ts)?;
+    user_refresh_stake(farm_state, new_user_state, ts)?;
+
+    migrate_legacy_withdrawal_ticket(farm_state, old_user_state);
+    migrate_legacy_withdrawal_ticket(farm_state, new_user_state);
+
+    let old_active_stake = old_user_state.get_active_stake_decimal();
+    let requested_stake = if requested_amount == u64::MAX {
+        old_active_stake
+    } else {
+        stake_ops::convert_amount_to_stake(
+            requested_amount,
+            farm_state.get_total_active_stake_decimal(),
+            farm_state.total_staked_amount,
+        )
+    };
+    let full_transfer = requested_stake >= old_active_stake;
+    let stake_transferred = cmp::min(requested_stake, old_active_stake);
+    require!(
+        stake_transferred > Decimal::zero()
+            || (full_transfer
+                && (old_user_state.pending_deposit_stake_scaled > 0
+                    || old_user_state.pending_withdrawal_unstake_scaled > 0)),
+        FarmError::NothingToUnstake
+    );
+
+    let new_position_empty = new_user_state.active_stake_scaled == 0
+        && new_user_state.pending_deposit_stake_scaled == 0
+        && new_user_state.pending_withdrawal_unstake_scaled == 0;
+    require!(
+        new_position_empty
+            || !transfer_extends_receiver_lock(farm_state, old_user_state, new_user_state, ts),
+        FarmError::TransferWouldExtendLock
+    );
+    if full_transfer {
+        check_transfer_queue_capacity(old_user_state, new_user_state)?;
+    }
+
+    let amount_transferred = stake_ops::convert_stake_to_amount(
+        stake_transferred,
+        farm_state.get_total_active_stake_decimal(),
+        farm_state.total_staked_amount,
+        false,
+    );
+
+    move_active_stake(
+        farm_state,
+        old_user_state,
+        new_user_state,
+        stake_transferred,
+    )?;
+
+    for reward_index in 0..farm_state.num_reward_tokens as usize {
+        let old_unclaimed = old_user_state.rewards_issued_unclaimed[reward_index];
+        let unclaimed_transferred = if full_transfer {
+            old_unclaimed
+        } else {
+            full_decimal_mul_div(stake_transferred, old_unclaimed, old_active_stake)
+                .try_floor()
+                .map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?
+        };
+        if unclaimed_transferred == 0 {
+            continue;
+        }
+
+        if new_user_state.vesting_amount[reward_index] == 0 {
+            new_user_state.last_claim_ts[reward_index] =
+                if new_user_state.rewards_issued_unclaimed[reward_index] == 0 {
+                    old_user_state.last_claim_ts[reward_index]
+                } else {
+                    cmp::max(
+                        new_user_state.last_claim_ts[reward_index],
+                        old_user_state.last_claim_ts[reward_index],
+                    )
+                };
+        }
+        old_user_state.rewards_issued_unclaimed[reward_index] -= unclaimed_transferred;
+        new_user_state.rewards_issued_unclaimed[reward_index] = new_user_state
+            .rewards_issued_unclaimed[reward_index]
+            .checked_add(unclaimed_transferred)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    }
+
+    if full_transfer {
+        migrate_legacy_deposit_lot(farm_state, old_user_state);
+        migrate_legacy_deposit_lot(farm_state, new_user_state);
+
+        for lot in old_user_state.pending_deposit_lots {
+            if !lot.is_empty() {
+                add_deposit_lot(new_user_state, lot.amount, lot.activation_ts)?;
+            }
+        }
+        new_user_state.pending_deposit_stake_scaled = new_user_state
+            .pending_deposit_stake_scaled
+            .checked_add(old_user_state.pending_deposit_stake_scaled)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        old_user_state.pending_deposit_lots = [DepositLot::default(); MAX_DEPOSIT_LOTS];
+        old_user_state.pending_deposit_stake_scaled = 0;
+        old_user_state.pending_deposit_stake_ts = 0;
+
+        for ticket in old_user_state.pending_withdrawal_tickets {
+            if !ticket.is_empty() {
+                add_withdrawal_ticket(new_user_state, ticket.amount, ticket.maturity_ts, ts)?;
+            }
+        }
+        new_user_state.pending_withdrawal_unstake_scaled = new_user_state
+            .pending_withdrawal_unstake_scaled
+            .checked_add(old_user_state.pending_withdrawal_unstake_scaled)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        old_user_state.pending_withdrawal_tickets =
+            [WithdrawalTicket::default(); MAX_WITHDRAWAL_TICKETS];
+        old_user_state.pending_withdrawal_unstake_scaled = 0;
+        old_user_state.pending_withdrawal_unstake_ts = 0;
+    }
+
+    if new_position_empty {
+        new_user_state.last_stake_ts = old_user_state.last_stake_ts;
+        new_user_state.lock_end_ts = old_user_state.lock_end_ts;
+    } else {
+        new_user_state.last_stake_ts =
+            cmp::max(new_user_state.last_stake_ts, old_user_state.last_stake_ts);
+        new_user_state.lock_end_ts =
+            cmp::max(new_user_state.lock_end_ts, old_user_state.lock_end_ts);
+    }
+
+    check_receipt_backing(old_user_state)?;
+
+    refresh_user_boost(farm_state, old_user_state, ts)?;
+    refresh_user_boost(farm_state, new_user_state, ts)?;
+
+    xmsg!(
+        "farm_operations::transfer_position stake_transferred={} amount_transferred={} full_transfer={}",
+        stake_transferred,
+        amount_transferred,
+        full_transfer
+    );
+
+    Ok(TransferPositionEffects {
+        stake_transferred,
+        amount_transferred,
+        full_transfer,
+    })
+}
+
+fn effective_lock_end_ts(farm_state: &FarmState, user_state: &UserState, ts: u64) -> u64 {
+    let locking_start_ts = match farm_state.get_locking_mode() {
+        LockingMode::None => None,
+        LockingMode::WithExpiry => Some(farm_state.locking_start_timestamp),
+        LockingMode::Continuous => Some(user_state.last_stake_ts),
+    };
+    let farm_lock_end_ts = match locking_start_ts {
+        Some(locking_start_ts) if ts >= locking_start_ts => {
+            locking_start_ts.saturating_add(farm_state.locking_duration)
+        }
+        _ => 0,
+    };
+    cmp::max(user_state.lock_end_ts, farm_lock_end_ts)
+}
+
+fn continuous_lock_end_ts(farm_state: &FarmState, user_state: &UserState) -> u64 {
+    match farm_state.get_locking_mode() {
+        LockingMode::Continuous => user_state
+            .last_stake_ts
+            .saturating_add(farm_state.locking_duration),
+        LockingMode::None | LockingMode::WithExpiry => 0,
+    }
+}
+
+fn transfer_extends_receiver_lock(
+    farm_state: &FarmState,
+    old_user_state: &UserState,
+    new_user_state: &UserState,
+    ts: u64,
+) -> bool {
+    continuous_lock_end_ts(farm_state, old_user_state)
+        > cmp::max(continuous_lock_end_ts(farm_state, new_user_state), ts)
+        || old_user_state.lock_end_ts > cmp::max(new_user_state.lock_end_ts, ts)
+}
+
+fn check_transfer_queue_capacity(
+    old_user_state: &UserState,
+    new_user_state: &UserState,
+) -> Result<()> {
+    let new_lots = &new_user_state.pending_deposit_lots;
+    let lots_needed = new_lots.iter().filter(|lot| !lot.is_empty()).count()
+        + old_user_state
+            .pending_deposit_lots
+            .iter()
+            .filter(|lot| {
+                !lot.is_empty()
+                    && !new_lots.iter().any(|new_lot| {
+                        !new_lot.is_empty() && new_lot.activation_ts == lot.activation_ts
+                    })
+            })
+            .count();
+
+    let new_tickets = &new_user_state.pending_withdrawal_tickets;
+    let tickets_needed = new_tickets
+        .iter()
+        .filter(|ticket| !ticket.is_empty())
+        .count()
+        + old_user_state
+            .pending_withdrawal_tickets
+            .iter()
+            .filter(|ticket| {
+                !ticket.is_empty()
+                    && !new_tickets.iter().any(|new_ticket| {
+                        !new_ticket.is_empty() && new_ticket.maturity_ts == ticket.maturity_ts
+                    })
+            })
+            .count();
+
+    xmsg!(
+        "farm_operations::transfer_position lots_needed={} tickets_needed={}",
+        lots_needed,
+        tickets_needed
+    );
+    require!(
+        lots_needed <= MAX_DEPOSIT_LOTS && tickets_needed <= MAX_WITHDRAWAL_TICKETS,
+        FarmError::TransferPendingQueueFull
+    );
+
+    Ok(())
+}
+
+fn move_active_stake(
+    farm_state: &mut FarmState,
+    from_user_state: &mut UserState,
+    to_user_state: &mut UserState,
+    stake_shares: Decimal,
+) -> Result<()> {
+    let stake_shares_scaled: u128 = stake_shares
+        .to_scaled_val()
+        .map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    from_user_state.active_stake_scaled = from_user_state
+        .active_stake_scaled
+        .checked_sub(stake_shares_scaled)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    update_user_rewards_tally_on_stake_decrease(farm_state, from_user_state, stake_shares)?;
+
+    to_user_state.active_stake_scaled = to_user_state
+        .active_stake_scaled
+        .checked_add(stake_shares_scaled)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    update_user_rewards_tally_on_stake_increase(farm_state, to_user_state, stake_shares)?;
+
+    Ok(())
+}
+
+fn receipt_backing_amount(user_state: &UserState) -> Result<u64> {
+    let amount = user_state
+        .get_active_stake_decimal()
+        .try_floor()
+        .map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?;
+    Ok(amount)
+}
+
+fn check_receipt_backing(user_state: &UserState) -> Result<()> {
+    require!(
+        user_state.receipt_amount <= receipt_backing_amount(user_state)?,
+        FarmError::ReceiptBackedStake
+    );
+    Ok(())
+}
+
+pub fn initialize_receipt_mint(farm_state: &mut FarmState, receipt_mint: Pubkey) -> Result<()> {
+    xmsg!(
+        "farm_operations::initialize_receipt_mint receipt_mint={}",
+        receipt_mint
+    );
+    require!(!farm_state.is_delegated(), FarmError::FarmDelegated);
+    require!(
+        !farm_state.has_receipt_mint(),
+        FarmError::ReceiptMintAlreadyInitialized
+    );
+    require!(
+        farm_state.deposit_warmup_period == 0,
+        FarmError::ReceiptMintRequiresNoWarmup
+    );
+
+    farm_state.receipt_mint = receipt_mint;
+
+    Ok(())
+}
+
+pub fn mint_receipt(user_state: &mut UserState, active_stake_scaled_before: u128) -> Result<u64> {
+    let backing_before: u64 = Decimal::from_scaled_val(active_stake_scaled_before)
+        .try_floor()
+        .map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?;
+    let backing_after = receipt_backing_amount(user_state)?;
+    let amount = backing_after.saturating_sub(backing_before);
+
+    user_state.receipt_amount = user_state
+        .receipt_amount
+        .checked_add(amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    xmsg!(
+        "farm_operations::mint_receipt amount={} receipt_amount={}",
+        amount,
+        user_state.receipt_amount
+    );
+
+    Ok(amount)
+}
+
+pub fn burn_receipt(user_state: &mut UserState) -> Result<u64> {
+    let backing = receipt_backing_amount(user_state)?;
+    let amount = user_state.receipt_amount.saturating_sub(backing);
+
+    user_state.receipt_amount -= amount;
+    xmsg!(
+        "farm_operations::burn_receipt amount={} receipt_amount={}",
+        amount,
+        user_state.receipt_amount
+    );
+
+    Ok(amount)
+}
+
+pub fn sync_receipt(
+    farm_state: &mut FarmState,
+    sender_user_state: &mut UserState,
+    receiver_user_state: &mut UserState,
+    scope_price: Option<DatedPrice>,
+    sender_balance: u64,
+    receiver_balance: u64,
+    ts: u64,
+) -> Result<u64> {
+    let sender_deficit = sender_user_state
+        .receipt_amount
+        .saturating_sub(sender_balance);
+    let receiver_surplus = receiver_balance.saturating_sub(receiver_user_state.receipt_amount);
+    let amount = cmp::min(sender_deficit, receiver_surplus);
+    xmsg!(
+        "farm_operations::sync_receipt sender_deficit={} receiver_surplus={} amount={}",
+        sender_deficit,
+        receiver_surplus,
+        amount
+    );
+    require!(amount > 0, FarmError::NothingToSyncReceipt);
+    require!(!farm_state.is_frozen(), FarmError::FarmFrozen);
+    require!(
+        !farm_state.is_paused(PauseFlag::Stake) && !farm_state.is_paused(PauseFlag::Unstake),
+        FarmError::OperationPaused
+    );
+    require!(
+        effective_lock_end_ts(farm_state, sender_user_state, ts) <= ts,
+        FarmError::UserStakeLocked
+    );
+
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+
+    user_refresh_all_rewards(farm_state, sender_user_state, ts)?;
+    user_refresh_all_rewards(farm_state, receiver_user_state, ts)?;
+    refresh_user_boost(farm_state, sender_user_state, ts)?;
+    refresh_user_boost(farm_state, receiver_user_state, ts)?;
+
+    move_active_stake(
+        farm_state,
+        sender_user_state,
+        receiver_user_state,
+        Decimal::from(amount),
+    )?;
 
-        *reward_tally = new_reward_tally_decimal_scaled;
-    }
+    sender_user_state.receipt_amount -= amount;
+    receiver_user_state.receipt_amount += amount;
 
-    Ok(())
+    refresh_user_boost(farm_state, receiver_user_state, ts)?;
+
+    Ok(amount)
 }
 
 pub fn withdraw_unstaked_deposits(
@@ -835,20 +2225,191 @@ pub fn withdraw_unstaked_deposits(
     user_state: &mut UserState,
     ts: u64,
 ) -> Result<WithdrawEffects> {
-    require!(
-        user_state.pending_withdrawal_unstake_ts <= ts,
-        FarmError::UnstakeNotElapsed
-    );
     require!(
         user_state.pending_withdrawal_unstake_scaled > 0,
         FarmError::NothingToWithdraw
     );
 
-    let amount_to_withdraw = stake_ops::remove_pending_withdrawal_stake(user_state, farm_state)?;
+    migrate_legacy_withdrawal_ticket(farm_state, user_state);
+
+    let mut matured_weight: u64 = 0;
+    let mut total_weight: u64 = 0;
+    for ticket in user_state.pending_withdrawal_tickets.iter() {
+        if ticket.maturity_ts <= ts {
+            matured_weight = matured_weight
+                .checked_add(ticket.amount)
+                .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        }
+        total_weight = total_weight
+            .checked_add(ticket.amount)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    }
+    require!(matured_weight > 0, FarmError::UnstakeNotElapsed);
+
+    let pending_withdrawal_unstake = user_state.get_pending_withdrawal_unstake_decimal();
+    let stake_to_withdraw = if matured_weight == total_weight {
+        pending_withdrawal_unstake
+    } else {
+        full_decimal_mul_div(
+            pending_withdrawal_unstake,
+            matured_weight,
+            Decimal::from(total_weight),
+        )
+    };
+
+    xmsg!(
+        "farm_operations::withdraw_unstaked_deposits matured_weight={} total_weight={} stake_to_withdraw={}",
+        matured_weight,
+        total_weight,
+        stake_to_withdraw
+    );
+
+    let amount_to_withdraw =
+        stake_ops::remove_pending_withdrawal_stake(user_state, farm_state, stake_to_withdraw)?;
+
+    let mut remaining_tickets = [WithdrawalTicket::default(); MAX_WITHDRAWAL_TICKETS];
+    for (remaining_ticket, ticket) in remaining_tickets.iter_mut().zip(
+        user_state
+            .pending_withdrawal_tickets
+            .iter()
+            .filter(|ticket| !ticket.is_empty() && ticket.maturity_ts > ts),
+    ) {
+        *remaining_ticket = *ticket;
+    }
+    user_state.pending_withdrawal_tickets = remaining_tickets;
 
     Ok(WithdrawEffects { amount_to_withdraw })
 }
 
+fn migrate_legacy_deposit_lot(farm_state: &FarmState, user_state: &mut UserState) {
+    if user_state.pending_deposit_stake_scaled == 0
+        || user_state
+            .pending_deposit_lots
+            .iter()
+            .any(|lot| !lot.is_empty())
+    {
+        return;
+    }
+
+    let amount = stake_ops::convert_stake_to_amount(
+        user_state.get_pending_deposit_stake_decimal(),
+        farm_state.get_total_pending_stake_decimal(),
+        farm_state.total_pending_amount,
+        false,
+    );
+    user_state.pending_deposit_lots[0] = DepositLot {
+        amount: cmp::max(amount, 1),
+        activation_ts: user_state.pending_deposit_stake_ts,
+    };
+}
+
+fn add_deposit_lot(user_state: &mut UserState, amount: u64, activation_ts: u64) -> Result<()> {
+    let lots = &mut user_state.pending_deposit_lots;
+
+    let lot_idx = if let Some(idx) = lots
+        .iter()
+        .position(|lot| !lot.is_empty() && lot.activation_ts == activation_ts)
+    {
+        idx
+    } else if let Some(idx) = lots.iter().position(|lot| lot.is_empty()) {
+        lots[idx].activation_ts = activation_ts;
+        idx
+    } else {
+        let idx = (0..MAX_DEPOSIT_LOTS)
+            .max_by_key(|idx| lots[*idx].activation_ts)
+            .unwrap();
+        xmsg!(
+            "farm_operations::stake deposit lots full, merging into lot {} old ts={}",
+            idx,
+            lots[idx].activation_ts
+        );
+        lots[idx].activation_ts = cmp::max(lots[idx].activation_ts, activation_ts);
+        idx
+    };
+
+    lots[lot_idx].amount = lots[lot_idx]
+        .amount
+        .checked_add(amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    user_state.pending_deposit_stake_ts = lots
+        .iter()
+        .map(|lot| lot.activation_ts)
+        .max()
+        .unwrap_or(activation_ts);
+
+    Ok(())
+}
+
+fn migrate_legacy_withdrawal_ticket(farm_state: &FarmState, user_state: &mut UserState) {
+    if user_state.pending_withdrawal_unstake_scaled == 0
+        || user_state
+            .pending_withdrawal_tickets
+            .iter()
+            .any(|ticket| !ticket.is_empty())
+    {
+        return;
+    }
+
+    let amount = stake_ops::convert_stake_to_amount(
+        user_state.get_pending_withdrawal_unstake_decimal(),
+        farm_state.get_total_pending_stake_decimal(),
+        farm_state.total_pending_amount,
+        false,
+    );
+    user_state.pending_withdrawal_tickets[0] = WithdrawalTicket {
+        amount: cmp::max(amount, 1),
+        maturity_ts: user_state.pending_withdrawal_unstake_ts,
+    };
+}
+
+fn add_withdrawal_ticket(
+    user_state: &mut UserState,
+    amount: u64,
+    maturity_ts: u64,
+    ts: u64,
+) -> Result<()> {
+    let tickets = &mut user_state.pending_withdrawal_tickets;
+
+    let ticket_idx = if let Some(idx) = tickets
+        .iter()
+        .position(|ticket| !ticket.is_empty() && ticket.maturity_ts == maturity_ts)
+    {
+        idx
+    } else if let Some(idx) = tickets.iter().position(|ticket| ticket.is_empty()) {
+        tickets[idx].maturity_ts = maturity_ts;
+        idx
+    } else {
+        if tickets.iter().any(|ticket| ticket.maturity_ts <= ts) {
+            xmsg!("farm_operations::unstake withdrawal tickets full and some elapsed but not withdrawn yet");
+            return err!(FarmError::PendingWithdrawalNotWithdrawnYet);
+        }
+        let idx = (0..MAX_WITHDRAWAL_TICKETS)
+            .max_by_key(|idx| tickets[*idx].maturity_ts)
+            .unwrap();
+        xmsg!(
+            "farm_operations::unstake withdrawal tickets full, merging into ticket {} old ts={}",
+            idx,
+            tickets[idx].maturity_ts
+        );
+        tickets[idx].maturity_ts = cmp::max(tickets[idx].maturity_ts, maturity_ts);
+        idx
+    };
+
+    tickets[ticket_idx].amount = tickets[ticket_idx]
+        .amount
+        .checked_add(amount)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    user_state.pending_withdrawal_unstake_ts = tickets
+        .iter()
+        .map(|ticket| ticket.maturity_ts)
+        .max()
+        .unwrap_or(maturity_ts);
+
+    Ok(())
+}
+
 pub fn refresh_global_reward(
     farm_state: &mut FarmState,
     scope_price: Option<DatedPrice>,
@@ -861,7 +2422,11 @@ pub fn refresh_global_reward(
         return Ok(());
     }
 
-    if farm_state.total_active_stake_scaled == 0 {
+    if farm_state.total_active_stake_scaled == 0
+        || farm_state.is_frozen()
+        || farm_state.is_paused(PauseFlag::RewardIssuance)
+        || farm_state.is_decommissioning()
+    {
        
        
         farm_state.reward_infos[reward_index].last_issuance_ts = ts;
@@ -872,8 +2437,11 @@ pub fn refresh_global_reward(
        
         let cumulative_amt = (reward_info
             .reward_schedule_curve
-            .get_cumulative_amount_issued_since_last_ts(reward_info.last_issuance_ts, ts)?)
-            as u128;
+            .get_cumulative_amount_issued_since_last_ts(
+                reward_info.reward_curve_mode(),
+                reward_info.last_issuance_ts,
+                ts,
+            )?) as u128;
 
        
        
@@ -969,7 +2537,9 @@ pub fn refresh_global_reward(
            
             Decimal::from(rewards) / farm_state.total_active_stake_scaled
         } else {
-            Decimal::from(rewards) / farm_state.get_total_active_stake_decimal()
+            Decimal::from(rewards)
+                / (farm_state.get_total_active_stake_decimal()
+                    + farm_state.get_total_boosted_stake_decimal())
         };
 
         reward_per_share = reward_per_share + added_reward_per_share;
@@ -994,8 +2564,96 @@ pub fn refresh_global_rewards(
     Ok(())
 }
 
+pub fn update_pause_flags(
+    farm_state: &mut FarmState,
+    scope_price: Option<DatedPrice>,
+    pause_flags: u64,
+    ts: u64,
+) -> Result<()> {
+    xmsg!(
+        "farm_operations::update_pause_flags pause_flags={:#b} prev={:#b}",
+        pause_flags,
+        farm_state.pause_flags
+    );
+    require!(
+        pause_flags & !PauseFlag::ALL == 0,
+        FarmError::InvalidPauseFlags
+    );
+
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+    farm_state.pause_flags = pause_flags;
+
+    Ok(())
+}
+
+pub fn update_farm_frozen(
+    farm_state: &mut FarmState,
+    scope_price: Option<DatedPrice>,
+    frozen: bool,
+    ts: u64,
+) -> Result<()> {
+    xmsg!(
+        "farm_operations::update_farm_frozen frozen={} prev={}",
+        frozen,
+        farm_state.is_farm_frozen
+    );
+    require!(
+        farm_state.is_frozen() != frozen,
+        FarmError::FarmFrozenStateUnchanged
+    );
+
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+    farm_state.is_farm_frozen = frozen as u8;
+
+    Ok(())
+}
+
+pub fn start_decommission(
+    farm_state: &mut FarmState,
+    scope_price: Option<DatedPrice>,
+    ts: u64,
+) -> Result<()> {
+    xmsg!("farm_operations::start_decommission ts={}", ts);
+    require!(
+        !farm_state.is_decommissioning(),
+        FarmError::FarmDecommissioning
+    );
+
+    refresh_global_rewards(farm_state, scope_price, ts)?;
+    farm_state.is_decommissioning = true as u8;
+
+    Ok(())
+}
+
+pub fn decommission(farm_state: &FarmState) -> Result<()> {
+    xmsg!(
+        "farm_operations::decommission num_users={} total_staked_amount={}",
+        farm_state.num_users,
+        farm_state.total_staked_amount
+    );
+    require!(
+        farm_state.is_decommissioning(),
+        FarmError::FarmNotDecommissioning
+    );
+    require!(
+        farm_state.num_users == 0
+            && farm_state.total_staked_amount == 0
+            && farm_state.total_active_stake_scaled == 0
+            && farm_state.total_pending_stake_scaled == 0
+            && farm_state.total_pending_amount == 0,
+        FarmError::FarmNotEmpty
+    );
+    require!(
+        farm_state.num_reward_distributions == 0,
+        FarmError::FarmHasOpenDistributions
+    );
+
+    Ok(())
+}
+
 pub fn deposit_to_farm_vault(farm_state: &mut FarmState, amount: u64) -> Result<()> {
     xmsg!("farm_operations::deposit_to_farm_vault amount={}", amount);
+    require!(!farm_state.is_frozen(), FarmError::FarmFrozen);
     stake_ops::increase_total_amount(farm_state, amount).map_err(Into::into)
 }
 
@@ -1020,6 +2678,166 @@ pub fn withdraw_slashed_amount(farm_state: &mut FarmState) -> Result<u64> {
     Ok(amount)
 }
 
+fn boost_remaining_lock(farm_state: &FarmState, user_state: &UserState, ts: u64) -> u64 {
+    if farm_state.is_delegated() || !farm_state.is_boost_enabled() || user_state.lock_end_ts <= ts {
+        0
+    } else {
+        cmp::min(
+            user_state.lock_end_ts - ts,
+            farm_state.boost_max_lock_duration,
+        )
+    }
+}
+
+fn boost_remaining_lock_integral(
+    farm_state: &FarmState,
+    user_state: &UserState,
+    ts: u64,
+) -> Decimal {
+    if user_state.lock_end_ts <= ts {
+        return Decimal::zero();
+    }
+    let remaining = u128::from(user_state.lock_end_ts - ts);
+    let max_lock = u128::from(farm_state.boost_max_lock_duration);
+    if remaining <= max_lock {
+        Decimal::from(remaining) * remaining / 2u128
+    } else {
+        Decimal::from(max_lock) * max_lock / 2u128
+            + Decimal::from(max_lock) * (remaining - max_lock)
+    }
+}
+
+fn boost_decay_forfeited_reward(
+    farm_state: &FarmState,
+    user_state: &UserState,
+    reward: u64,
+    ts: u64,
+) -> Result<u64> {
+    let boost_refresh_ts = user_state.boost_refresh_ts;
+    if reward == 0
+        || user_state.boosted_stake_scaled == 0
+        || boost_refresh_ts == 0
+        || ts <= boost_refresh_ts
+    {
+        return Ok(0);
+    }
+
+    let boosted_stake = user_state.get_boosted_stake_decimal();
+    let boosted_reward = Decimal::from(reward) * boosted_stake
+        / (user_state.get_active_stake_decimal() + boosted_stake);
+
+    let remaining_lock_at_refresh = boost_remaining_lock(farm_state, user_state, boost_refresh_ts);
+    let earned_boosted_reward = if remaining_lock_at_refresh == 0 {
+        Decimal::zero()
+    } else {
+        let earned_lock_integral =
+            boost_remaining_lock_integral(farm_state, user_state, boost_refresh_ts)
+                - boost_remaining_lock_integral(farm_state, user_state, ts);
+        let full_lock_integral = Decimal::from(remaining_lock_at_refresh) * (ts - boost_refresh_ts);
+        cmp::min(
+            boosted_reward * earned_lock_integral / full_lock_integral,
+            boosted_reward,
+        )
+    };
+
+    let forfeited: u64 = (boosted_reward - earned_boosted_reward)
+        .try_floor()
+        .map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?;
+    Ok(cmp::min(forfeited, reward))
+}
+
+fn refresh_user_boost(
+    farm_state: &mut FarmState,
+    user_state: &mut UserState,
+    ts: u64,
+) -> Result<()> {
+    let boosted_stake_before = user_state.get_boosted_stake_decimal();
+    user_state.boost_refresh_ts = ts;
+
+    let remaining_lock = boost_remaining_lock(farm_state, user_state, ts);
+    let boosted_stake = if remaining_lock == 0 {
+        Decimal::zero()
+    } else {
+        let extra_multiplier_bps = u64_mul_div(
+            farm_state.boost_max_multiplier_bps - BPS_DIV_FACTOR,
+            remaining_lock,
+            farm_state.boost_max_lock_duration,
+        );
+        full_decimal_mul_div(
+            user_state.get_active_stake_decimal(),
+            extra_multiplier_bps,
+            Decimal::from(BPS_DIV_FACTOR),
+        )
+    };
+
+    if boosted_stake == boosted_stake_before {
+        return Ok(());
+    }
+
+    xmsg!(
+        "farm_operations::refresh_user_boost lock_end_ts={} boosted_stake_before={} boosted_stake={}",
+        user_state.lock_end_ts,
+        boosted_stake_before,
+        boosted_stake
+    );
+
+    farm_state.set_total_boosted_stake_decimal(
+        farm_state.get_total_boosted_stake_decimal() - boosted_stake_before + boosted_stake,
+    );
+    user_state.set_boosted_stake_decimal(boosted_stake);
+
+    for index in 0..farm_state.num_reward_tokens as usize {
+        let reward_per_share = farm_state.reward_infos[index].get_reward_per_share_decimal();
+        let rewards_tally = user_state.get_rewards_tally_decimal(index);
+
+        let new_reward_tally = if boosted_stake > boosted_stake_before {
+            rewards_tally + (boosted_stake - boosted_stake_before) * reward_per_share
+        } else {
+            let tally_loss = (boosted_stake_before - boosted_stake) * reward_per_share;
+            if tally_loss > rewards_tally {
+                Decimal::zero()
+            } else {
+                rewards_tally - tally_loss
+            }
+        };
+
+        user_state.rewards_tally_scaled[index] = new_reward_tally
+            .to_scaled_val()
+            .map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?;
+    }
+
+    Ok(())
+}
+
+fn update_user_rewards_tally_on_stake_decrease(
+    farm_state: &FarmState,
+    user_state: &mut UserState,
+    removed_shares: Decimal,
+) -> Result<()> {
+    for i in 0..farm_state.num_reward_tokens as usize {
+        let reward_tally = &mut user_state.rewards_tally_scaled[i];
+        let reward_info = &farm_state.reward_infos[i];
+
+        let reward_tally_decimal = Decimal::from_scaled_val(*reward_tally);
+        let tally_loss = removed_shares * reward_info.get_reward_per_share_decimal();
+
+       
+       
+        require_gt!(
+            reward_tally_decimal + Decimal::one(),
+            tally_loss,
+            FarmError::IntegerOverflow
+        );
+        let reward_tally_scaled: u128 = reward_tally_decimal.to_scaled_val().unwrap();
+        let tally_loss_scaled: u128 = tally_loss.to_scaled_val().unwrap();
+        let new_reward_tally_decimal_scaled = reward_tally_scaled.saturating_sub(tally_loss_scaled);
+
+        *reward_tally = new_reward_tally_decimal_scaled;
+    }
+
+    Ok(())
+}
+
 fn update_user_rewards_tally_on_stake_increase(
     farm_state: &mut FarmState,
     user_state: &mut UserState,
@@ -1054,3 +2872,315 @@ fn update_user_rewards_tally_on_stake_increase(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_with_stake(stake: u64) -> UserState {
+        let mut user_state = UserState::default();
+        user_state.set_active_stake_decimal(Decimal::from(stake));
+        user_state
+    }
+
+    fn farm_with_stake(stake: u64) -> FarmState {
+        let mut farm_state = FarmState::default();
+        farm_state.set_total_active_stake_decimal(Decimal::from(stake));
+        farm_state.total_staked_amount = stake;
+        farm_state
+    }
+
+    #[test]
+    fn test_add_withdrawal_ticket_full_queue_never_shortens_maturity() {
+        let mut user_state = UserState::default();
+        for ts in [110, 120, 130, 140] {
+            add_withdrawal_ticket(&mut user_state, 1, ts, 100).unwrap();
+        }
+
+        add_withdrawal_ticket(&mut user_state, 5, 125, 100).unwrap();
+        let ticket = user_state.pending_withdrawal_tickets[3];
+        assert_eq!(ticket.maturity_ts, 140);
+        assert_eq!(ticket.amount, 6);
+
+        add_withdrawal_ticket(&mut user_state, 5, 150, 100).unwrap();
+        let ticket = user_state.pending_withdrawal_tickets[3];
+        assert_eq!(ticket.maturity_ts, 150);
+        assert_eq!(ticket.amount, 11);
+        assert_eq!(user_state.pending_withdrawal_unstake_ts, 150);
+
+        assert_eq!(
+            add_withdrawal_ticket(&mut user_state, 5, 160, 110).unwrap_err(),
+            FarmError::PendingWithdrawalNotWithdrawnYet.into()
+        );
+    }
+
+    #[test]
+    fn test_add_deposit_lot_full_queue_never_shortens_activation() {
+        let mut user_state = UserState::default();
+        for ts in [10, 20, 30, 40] {
+            add_deposit_lot(&mut user_state, 1, ts).unwrap();
+        }
+
+        add_deposit_lot(&mut user_state, 5, 25).unwrap();
+        let lot = user_state.pending_deposit_lots[3];
+        assert_eq!(lot.activation_ts, 40);
+        assert_eq!(lot.amount, 6);
+
+        add_deposit_lot(&mut user_state, 5, 50).unwrap();
+        let lot = user_state.pending_deposit_lots[3];
+        assert_eq!(lot.activation_ts, 50);
+        assert_eq!(lot.amount, 11);
+        assert_eq!(user_state.pending_deposit_stake_ts, 50);
+    }
+
+    #[test]
+    fn test_boost_decay_forfeits_unearned_boost() {
+        let mut farm_state = farm_with_stake(1000);
+        farm_state.boost_max_multiplier_bps = 2 * BPS_DIV_FACTOR;
+        farm_state.boost_max_lock_duration = 100;
+        let mut user_state = user_with_stake(1000);
+        user_state.lock_end_ts = 200;
+
+        refresh_user_boost(&mut farm_state, &mut user_state, 100).unwrap();
+        assert_eq!(
+            user_state.get_boosted_stake_decimal(),
+            Decimal::from(1000u64)
+        );
+        assert_eq!(user_state.boost_refresh_ts, 100);
+
+        assert_eq!(
+            boost_decay_forfeited_reward(&farm_state, &user_state, 2000, 100).unwrap(),
+            0
+        );
+        assert_eq!(
+            boost_decay_forfeited_reward(&farm_state, &user_state, 2000, 150).unwrap(),
+            250
+        );
+        assert_eq!(
+            boost_decay_forfeited_reward(&farm_state, &user_state, 2000, 200).unwrap(),
+            500
+        );
+        assert_eq!(
+            boost_decay_forfeited_reward(&farm_state, &user_state, 2000, 300).unwrap(),
+            750
+        );
+
+        refresh_user_boost(&mut farm_state, &mut user_state, 200).unwrap();
+        assert_eq!(user_state.boosted_stake_scaled, 0);
+        assert_eq!(farm_state.total_boosted_stake_scaled, 0);
+    }
+
+    #[test]
+    fn test_forfeit_unvested_rewards_keeps_released_part() {
+        let mut farm_state = FarmState {
+            num_reward_tokens: 1,
+            ..Default::default()
+        };
+        farm_state.reward_infos[0].forfeit_vesting_on_unstake = 1;
+        farm_state.reward_infos[0].rewards_vesting = 1000;
+        let mut user_state = UserState::default();
+        user_state.vesting_amount[0] = 1000;
+        user_state.vesting_duration_remaining[0] = 100;
+        user_state.last_claim_ts[0] = 100;
+
+        assert_eq!(releasable_vested_reward(&user_state, 0, 100), 0);
+        assert_eq!(releasable_vested_reward(&user_state, 0, 125), 250);
+        assert_eq!(releasable_vested_reward(&user_state, 0, 250), 1000);
+
+        forfeit_unvested_rewards(&mut farm_state, &mut user_state, 150).unwrap();
+        assert_eq!(user_state.vesting_amount[0], 500);
+        assert_eq!(user_state.vesting_duration_remaining[0], 0);
+        assert_eq!(releasable_vested_reward(&user_state, 0, 150), 500);
+        assert_eq!(farm_state.reward_infos[0].rewards_vesting, 500);
+        assert_eq!(farm_state.reward_infos[0].rewards_available, 500);
+    }
+
+    #[test]
+    fn test_forfeit_unvested_rewards_disabled_by_default() {
+        let mut farm_state = FarmState {
+            num_reward_tokens: 1,
+            ..Default::default()
+        };
+        farm_state.reward_infos[0].rewards_vesting = 1000;
+        let mut user_state = UserState::default();
+        user_state.vesting_amount[0] = 1000;
+        user_state.vesting_duration_remaining[0] = 100;
+        user_state.last_claim_ts[0] = 100;
+
+        forfeit_unvested_rewards(&mut farm_state, &mut user_state, 150).unwrap();
+        assert_eq!(user_state.vesting_amount[0], 1000);
+        assert_eq!(farm_state.reward_infos[0].rewards_vesting, 1000);
+    }
+
+    #[test]
+    fn test_transfer_rejects_extending_non_empty_receiver_lock() {
+        let mut farm_state = farm_with_stake(200);
+        let mut old_user_state = user_with_stake(100);
+        old_user_state.lock_end_ts = 500;
+        let mut new_user_state = user_with_stake(100);
+
+        assert_eq!(
+            transfer_position(
+                &mut farm_state,
+                &mut old_user_state,
+                &mut new_user_state,
+                None,
+                100,
+                100,
+            )
+            .unwrap_err(),
+            FarmError::TransferWouldExtendLock.into()
+        );
+
+        farm_state.locking_mode = LockingMode::Continuous as u64;
+        farm_state.locking_duration = 100;
+        old_user_state.lock_end_ts = 0;
+        old_user_state.last_stake_ts = 90;
+        new_user_state.last_stake_ts = 50;
+        assert!(transfer_extends_receiver_lock(
+            &farm_state,
+            &old_user_state,
+            &new_user_state,
+            100
+        ));
+        new_user_state.last_stake_ts = 95;
+        assert!(!transfer_extends_receiver_lock(
+            &farm_state,
+            &old_user_state,
+            &new_user_state,
+            100
+        ));
+    }
+
+    #[test]
+    fn test_transfer_to_empty_receiver_carries_lock() {
+        let mut farm_state = farm_with_stake(100);
+        let mut old_user_state = user_with_stake(100);
+        old_user_state.lock_end_ts = 500;
+        old_user_state.last_stake_ts = 90;
+        let mut new_user_state = UserState::default();
+
+        let effects = transfer_position(
+            &mut farm_state,
+            &mut old_user_state,
+            &mut new_user_state,
+            None,
+            100,
+            100,
+        )
+        .unwrap();
+        assert!(effects.full_transfer);
+        assert_eq!(effects.amount_transferred, 100);
+        assert_eq!(old_user_state.active_stake_scaled, 0);
+        assert_eq!(
+            new_user_state.get_active_stake_decimal(),
+            Decimal::from(100u64)
+        );
+        assert_eq!(new_user_state.lock_end_ts, 500);
+        assert_eq!(new_user_state.last_stake_ts, 90);
+    }
+
+    #[test]
+    fn test_transfer_queue_capacity() {
+        let mut old_user_state = UserState::default();
+        let mut new_user_state = UserState::default();
+        for ts in [10, 20] {
+            add_deposit_lot(&mut old_user_state, 1, ts).unwrap();
+        }
+        for ts in [20, 30, 40] {
+            add_deposit_lot(&mut new_user_state, 1, ts).unwrap();
+        }
+        check_transfer_queue_capacity(&old_user_state, &new_user_state).unwrap();
+
+        add_deposit_lot(&mut old_user_state, 1, 15).unwrap();
+        assert_eq!(
+            check_transfer_queue_capacity(&old_user_state, &new_user_state).unwrap_err(),
+            FarmError::TransferPendingQueueFull.into()
+        );
+
+        let old_user_state = UserState::default();
+        let mut new_user_state = UserState::default();
+        let mut sender = UserState::default();
+        for ts in [110, 120, 130] {
+            add_withdrawal_ticket(&mut new_user_state, 1, ts, 100).unwrap();
+        }
+        for ts in [140, 150] {
+            add_withdrawal_ticket(&mut sender, 1, ts, 100).unwrap();
+        }
+        check_transfer_queue_capacity(&old_user_state, &new_user_state).unwrap();
+        assert_eq!(
+            check_transfer_queue_capacity(&sender, &new_user_state).unwrap_err(),
+            FarmError::TransferPendingQueueFull.into()
+        );
+    }
+
+    #[test]
+    fn test_transfer_keeps_receiver_vesting_start() {
+        let mut farm_state = farm_with_stake(200);
+        farm_state.num_reward_tokens = 1;
+        farm_state.reward_infos[0].rewards_issued_unclaimed = 50;
+        farm_state.reward_infos[0].rewards_vesting = 1000;
+        let mut old_user_state = user_with_stake(100);
+        old_user_state.rewards_issued_unclaimed[0] = 50;
+        old_user_state.last_claim_ts[0] = 150;
+        let mut new_user_state = user_with_stake(100);
+        new_user_state.vesting_amount[0] = 1000;
+        new_user_state.vesting_duration_remaining[0] = 100;
+        new_user_state.last_claim_ts[0] = 100;
+
+        transfer_position(
+            &mut farm_state,
+            &mut old_user_state,
+            &mut new_user_state,
+            None,
+            100,
+            150,
+        )
+        .unwrap();
+        assert_eq!(new_user_state.rewards_issued_unclaimed[0], 50);
+        assert_eq!(new_user_state.last_claim_ts[0], 100);
+        assert_eq!(releasable_vested_reward(&new_user_state, 0, 150), 500);
+    }
+
+    #[test]
+    fn test_sync_receipt_requires_unlocked_sender() {
+        let mut farm_state = farm_with_stake(200);
+        farm_state.locking_mode = LockingMode::Continuous as u64;
+        farm_state.locking_duration = 100;
+        let mut sender = user_with_stake(100);
+        sender.receipt_amount = 100;
+        sender.last_stake_ts = 150;
+        let mut receiver = user_with_stake(100);
+        receiver.receipt_amount = 100;
+        receiver.last_stake_ts = 50;
+
+        assert_eq!(
+            sync_receipt(
+                &mut farm_state,
+                &mut sender,
+                &mut receiver,
+                None,
+                90,
+                110,
+                200
+            )
+            .unwrap_err(),
+            FarmError::UserStakeLocked.into()
+        );
+
+        let amount = sync_receipt(
+            &mut farm_state,
+            &mut sender,
+            &mut receiver,
+            None,
+            90,
+            110,
+            250,
+        )
+        .unwrap();
+        assert_eq!(amount, 10);
+        assert_eq!(receiver.receipt_amount, 110);
+        assert_eq!(receiver.get_active_stake_decimal(), Decimal::from(110u64));
+        assert_eq!(receiver.last_stake_ts, 50);
+    }
+}
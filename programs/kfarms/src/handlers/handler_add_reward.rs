@@ -7,6 +7,8 @@ use anchor_spl::{
 };
 
 use crate::{
+    dbg_msg,
+    events::AddRewardEvent,
     farm_operations,
     state::TimeUnit,
     types::AddRewardEffects,
@@ -24,33 +26,18 @@ pub fn process(ctx: Context<AddReward>, amount: u64, reward_index: u64) -> Resul
 
     let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
     let time_unit = farm_state.time_unit;
-    let reward_mint = &mut ctx.accounts.reward_mint;
+    let reward_mint = ctx.accounts.reward_mint.key();
     let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
     msg!(
         "AddReward farm_state {:?} amount {}, reward_index {} ts {}",
         ctx.accounts.farm_state.key(),
         amount,
         reward_index,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?)
-    );
-
-    let AddRewardEffects { reward_amount } = farm_operations::add_reward(
-        farm_state,
-        scope_price,
-        reward_mint.key(),
-        reward_index as usize,
-        amount,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
-    )?;
-
-   
-    msg!(
-        "add {} to reward {:?} index {}",
-        reward_amount,
-        reward_mint.key(),
-        reward_index
+        ts
     );
 
+    let vault_amount_before = ctx.accounts.reward_vault.amount;
     token_2022::transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -65,10 +52,53 @@ pub fn process(ctx: Context<AddReward>, amount: u64, reward_index: u64) -> Resul
                 mint: ctx.accounts.reward_mint.to_account_info().clone(),
             },
         ),
-        reward_amount,
+        amount,
         ctx.accounts.reward_mint.decimals,
     )?;
 
+    ctx.accounts.reward_vault.reload()?;
+    let amount_received = ctx
+        .accounts
+        .reward_vault
+        .amount
+        .checked_sub(vault_amount_before)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+    let transfer_fee = amount
+        .checked_sub(amount_received)
+        .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+
+    let rewards_available_before = farm_state.reward_infos[reward_index as usize].rewards_available;
+    let AddRewardEffects { reward_amount } = farm_operations::add_reward(
+        farm_state,
+        scope_price,
+        reward_mint,
+        reward_index as usize,
+        amount_received,
+        ts,
+    )?;
+
+    msg!(
+        "add {} to reward {:?} index {} transfer fee {}",
+        reward_amount,
+        reward_mint,
+        reward_index,
+        transfer_fee
+    );
+
+    let reward_info = &farm_state.reward_infos[reward_index as usize];
+    emit!(AddRewardEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        payer: ctx.accounts.payer.key(),
+        reward_index,
+        reward_mint: reward_info.token.mint,
+        amount: reward_amount,
+        transfer_fee,
+        rewards_available_before,
+        rewards_available_after: reward_info.rewards_available,
+        reward_per_share_scaled: reward_info.reward_per_share_scaled,
+        ts,
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChangeCancelledEvent,
+    state::{ConfigChangeTarget, PendingConfigChange},
+    utils::constraints::check_remaining_accounts,
+    FarmError, FarmState, GlobalConfig,
+};
+
+pub fn process(ctx: Context<CancelConfigChange>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let pending_config_change = &ctx.accounts.pending_config_change.load()?;
+    let global_config = &ctx.accounts.global_config.load()?;
+    let global_config_key = ctx.accounts.global_config.key();
+    let signer = ctx.accounts.signer.key();
+
+    let is_guardian = |guardian: Pubkey| guardian != Pubkey::default() && guardian == signer;
+
+    let is_authorized = match pending_config_change.target_kind() {
+        ConfigChangeTarget::FarmState => {
+            let farm_state_loader = ctx
+                .accounts
+                .farm_state
+                .as_ref()
+                .ok_or(FarmError::ConfigChangeTargetMismatch)?;
+            require_keys_eq!(
+                farm_state_loader.key(),
+                pending_config_change.target,
+                FarmError::ConfigChangeTargetMismatch
+            );
+            let farm_state = farm_state_loader.load()?;
+            require_keys_eq!(
+                farm_state.global_config,
+                global_config_key,
+                FarmError::ConfigChangeTargetMismatch
+            );
+
+            signer == pending_config_change.admin
+                || signer == farm_state.farm_admin
+                || is_guardian(farm_state.guardian)
+                || is_guardian(global_config.guardian)
+        }
+        ConfigChangeTarget::GlobalConfig => {
+            require_keys_eq!(
+                global_config_key,
+                pending_config_change.target,
+                FarmError::ConfigChangeTargetMismatch
+            );
+
+            signer == pending_config_change.admin
+                || signer == global_config.global_admin
+                || is_guardian(global_config.guardian)
+        }
+    };
+    require!(is_authorized, FarmError::InvalidConfigChangeCancelAuthority);
+
+    msg!(
+        "CancelConfigChange pending_config_change {:?} target {:?} mode {} signer {:?}",
+        ctx.accounts.pending_config_change.key(),
+        pending_config_change.target,
+        pending_config_change.mode,
+        signer
+    );
+
+    emit!(ConfigChangeCancelledEvent {
+        pending_config_change: ctx.accounts.pending_config_change.key(),
+        target: pending_config_change.target,
+        target_kind: pending_config_change.target_kind,
+        mode: pending_config_change.mode,
+        signer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: Receives the rent, verified with a has_one constraint in pending_config_change
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(mut,
+        has_one = admin,
+        close = admin,
+    )]
+    pub pending_config_change: AccountLoader<'info, PendingConfigChange>,
+
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    pub farm_state: Option<AccountLoader<'info, FarmState>>,
+}
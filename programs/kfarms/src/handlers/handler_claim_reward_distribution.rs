@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ClaimRewardDistributionEvent,
+    farm_operations,
+    state::{ClaimedBitmap, RewardDistribution},
+    utils::{constraints::check_remaining_accounts, consts::SIZE_CLAIMED_BITMAP_HEADER},
+    FarmState, UserState,
+};
+
+pub fn process(
+    ctx: Context<ClaimRewardDistribution>,
+    leaf_index: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let user_state = &mut ctx.accounts.user_state.load_mut()?;
+    let reward_distribution = &mut ctx.accounts.reward_distribution.load_mut()?;
+
+    let claimed_bitmap_info = ctx.accounts.claimed_bitmap.to_account_info();
+    let mut claimed_bitmap_data = claimed_bitmap_info.try_borrow_mut_data()?;
+
+    msg!(
+        "ClaimRewardDistribution reward_distribution {:?} user_state {:?} leaf_index {} amount {}",
+        ctx.accounts.reward_distribution.key(),
+        ctx.accounts.user_state.key(),
+        leaf_index,
+        amount
+    );
+
+    farm_operations::claim_reward_distribution(
+        farm_state,
+        user_state,
+        reward_distribution,
+        &mut claimed_bitmap_data[SIZE_CLAIMED_BITMAP_HEADER..],
+        leaf_index,
+        amount,
+        &proof,
+    )?;
+
+    let reward_index = reward_distribution.reward_index;
+    emit!(ClaimRewardDistributionEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        reward_distribution: ctx.accounts.reward_distribution.key(),
+        user_state: ctx.accounts.user_state.key(),
+        owner: user_state.owner,
+        reward_index,
+        leaf_index,
+        amount,
+        rewards_issued_unclaimed_after: user_state.rewards_issued_unclaimed[reward_index as usize],
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewardDistribution<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(mut,
+        has_one = farm_state,
+    )]
+    pub user_state: AccountLoader<'info, UserState>,
+
+    #[account(mut,
+        has_one = farm_state,
+        has_one = claimed_bitmap,
+    )]
+    pub reward_distribution: AccountLoader<'info, RewardDistribution>,
+
+    #[account(mut,
+        has_one = reward_distribution,
+    )]
+    pub claimed_bitmap: AccountLoader<'info, ClaimedBitmap>,
+}
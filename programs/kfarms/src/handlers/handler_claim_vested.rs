@@ -0,0 +1,140 @@
+use std::ops::Deref;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
+
+use crate::{
+    events::ClaimVestedEvent,
+    farm_operations, gen_signer_seeds_two,
+    handlers::handler_harvest_reward::check_owner_harvesting_permissionless,
+    state::TimeUnit,
+    token_operations,
+    utils::{
+        accessors::mint_transfer_fee,
+        constraints::{check_remaining_accounts, token_2022::validate_reward_token_extensions},
+        consts::*,
+    },
+    FarmError, FarmState, UserState,
+};
+
+pub fn process(ctx: Context<ClaimVested>, reward_index: u64) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+    validate_reward_token_extensions(&ctx.accounts.reward_mint.to_account_info())?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let time_unit = farm_state.time_unit;
+    let user_state = &mut ctx.accounts.user_state.load_mut()?;
+
+    require!(
+        reward_index < farm_state.num_reward_tokens,
+        FarmError::RewardIndexOutOfRange
+    );
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "ClaimVested user_state {:?}, farm_state {:?} reward_index {} ts {}",
+        ctx.accounts.user_state.key(),
+        ctx.accounts.farm_state.key(),
+        reward_index,
+        ts
+    );
+
+    let amount = farm_operations::claim_vested(farm_state, user_state, reward_index as usize, ts)?;
+
+    let transfer_fee = mint_transfer_fee(
+        &ctx.accounts.reward_mint.to_account_info(),
+        amount,
+        Clock::get()?.epoch,
+    )?;
+    msg!(
+        "owner {:?} amount {:?} transfer_fee {:?}",
+        user_state.owner,
+        amount,
+        transfer_fee
+    );
+
+    let farm_state_key = ctx.accounts.farm_state.key();
+    let signer_seeds: &[&[&[u8]]] = gen_signer_seeds_two!(
+        BASE_SEED_FARM_VAULTS_AUTHORITY,
+        farm_state_key,
+        farm_state.farm_vaults_authority_bump as u8
+    );
+
+    if amount > 0 {
+        token_operations::transfer_2022_from_vault(
+            amount,
+            signer_seeds,
+            &ctx.accounts.user_reward_token_account.to_account_info(),
+            &ctx.accounts.rewards_vault.to_account_info(),
+            &ctx.accounts.farm_vaults_authority,
+            &ctx.accounts.token_program,
+            &ctx.accounts.reward_mint.to_account_info(),
+        )?;
+    }
+
+    emit!(ClaimVestedEvent {
+        farm_state: farm_state_key,
+        user_state: ctx.accounts.user_state.key(),
+        owner: user_state.owner,
+        reward_index,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount,
+        transfer_fee,
+        vesting_amount_after: user_state.vesting_amount[reward_index as usize],
+        vesting_duration_remaining: user_state.vesting_duration_remaining[reward_index as usize],
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reward_index: u64)]
+pub struct ClaimVested<'info> {
+    #[account(
+        constraint = check_owner_harvesting_permissionless(payer.key(), farm_state.load()?.deref(), user_state.load()?.deref()) @ FarmError::HarvestingNotPermissionlessPayerMismatch,
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_state,
+    )]
+    pub user_state: AccountLoader<'info, UserState>,
+
+    #[account(
+        mut,
+        has_one = farm_vaults_authority
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    #[account(mut,
+        token::authority = user_state.load()?.owner,
+        token::mint = reward_mint.key(),
+        token::token_program = token_program
+    )]
+    pub user_reward_token_account: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    #[account(mut,
+        seeds = [BASE_SEED_REWARD_VAULT, farm_state.key().as_ref(), rewards_vault.mint.as_ref()],
+        bump,
+        constraint = rewards_vault.delegate.is_none() @ FarmError::RewardsVaultHasDelegate,
+        constraint = rewards_vault.close_authority.is_none() @ FarmError::RewardsVaultHasCloseAuthority,
+        constraint = rewards_vault.key() == farm_state.load()?.reward_infos[reward_index as usize].rewards_vault @ FarmError::RewardVaultMismatch,
+        token::mint = reward_mint,
+        token::token_program = token_program
+    )]
+    pub rewards_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// CHECK: Verified with a has_one constraint in farm state
+    #[account(
+        seeds = [BASE_SEED_FARM_VAULTS_AUTHORITY, farm_state.key().as_ref()],
+        bump,
+    )]
+    pub farm_vaults_authority: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::CloseRewardDistributionEvent,
+    farm_operations,
+    state::{ClaimedBitmap, RewardDistribution},
+    utils::constraints::check_remaining_accounts,
+    FarmState,
+};
+
+pub fn process(ctx: Context<CloseRewardDistribution>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let reward_distribution = &ctx.accounts.reward_distribution.load()?;
+
+    msg!(
+        "CloseRewardDistribution farm_state {:?} reward_distribution {:?}",
+        ctx.accounts.farm_state.key(),
+        ctx.accounts.reward_distribution.key()
+    );
+
+    let returned_amount =
+        farm_operations::close_reward_distribution(farm_state, reward_distribution)?;
+
+    emit!(CloseRewardDistributionEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        reward_distribution: ctx.accounts.reward_distribution.key(),
+        reward_index: reward_distribution.reward_index,
+        claimed_amount: reward_distribution.claimed_amount,
+        returned_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseRewardDistribution<'info> {
+    #[account(mut)]
+    pub farm_admin: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_admin,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(mut,
+        has_one = farm_state,
+        has_one = claimed_bitmap,
+        close = farm_admin,
+    )]
+    pub reward_distribution: AccountLoader<'info, RewardDistribution>,
+
+    #[account(mut,
+        has_one = reward_distribution,
+        close = farm_admin,
+    )]
+    pub claimed_bitmap: AccountLoader<'info, ClaimedBitmap>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::CloseUserStateEvent, farm_operations, utils::constraints::check_remaining_accounts,
+    FarmError, FarmState, UserState,
+};
+
+pub fn process(ctx: Context<CloseUserState>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let user_state = &ctx.accounts.user_state.load()?;
+    let authority = ctx.accounts.authority.key();
+
+    if !farm_state.is_delegated() {
+        require_keys_eq!(
+            authority,
+            user_state.owner,
+            FarmError::UserStateOwnerMismatch
+        );
+    } else {
+        require!(
+            farm_state.delegate_authority == authority
+                || farm_state.second_delegated_authority == authority,
+            FarmError::AuthorityFarmDelegateMissmatch
+        );
+    }
+
+    msg!(
+        "CloseUserState: user {} farm {} owner {}",
+        ctx.accounts.user_state.key(),
+        ctx.accounts.farm_state.key(),
+        user_state.owner
+    );
+
+    farm_operations::close_user(farm_state, user_state)?;
+
+    emit!(CloseUserStateEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        user_state: ctx.accounts.user_state.key(),
+        owner: user_state.owner,
+        authority,
+        num_users: farm_state.num_users,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseUserState<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: Receives the rent, verified with a has_one constraint in user_state
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    #[account(mut,
+        has_one = owner,
+        has_one = farm_state,
+        close = owner,
+    )]
+    pub user_state: AccountLoader<'info, UserState>,
+
+    #[account(mut)]
+    pub farm_state: AccountLoader<'info, FarmState>,
+}
@@ -0,0 +1,199 @@
+use std::ops::Deref;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
+
+use crate::{
+    events::CompoundRewardEvent,
+    farm_operations, gen_signer_seeds_two,
+    handlers::handler_harvest_reward::check_owner_harvesting_permissionless,
+    state::TimeUnit,
+    token_operations,
+    types::{HarvestEffects, StakeEffects},
+    utils::{
+        constraints::{check_remaining_accounts, token_2022::validate_base_token_extensions},
+        consts::*,
+        scope::load_scope_price,
+    },
+    FarmError, FarmState, GlobalConfig, UserState,
+};
+
+pub fn process(ctx: Context<CompoundReward>, reward_index: u64) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+    validate_base_token_extensions(&ctx.accounts.reward_mint.to_account_info())?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let time_unit = farm_state.time_unit;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+
+    let user_state = &mut ctx.accounts.user_state.load_mut()?;
+    let global_config = &ctx.accounts.global_config.load()?;
+
+    require!(
+        reward_index < farm_state.num_reward_tokens,
+        FarmError::RewardIndexOutOfRange
+    );
+    require!(!farm_state.is_delegated(), FarmError::FarmDelegated);
+    require_keys_eq!(
+        farm_state.reward_infos[reward_index as usize].token.mint,
+        farm_state.token.mint,
+        FarmError::CompoundRewardMintNotFarmToken
+    );
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "CompoundReward user_state {:?}, farm_state {:?} ts {}",
+        ctx.accounts.user_state.key(),
+        ctx.accounts.farm_state.key(),
+        ts
+    );
+    let HarvestEffects {
+        reward_user,
+        reward_treasury,
+        ..
+    } = farm_operations::harvest(
+        farm_state,
+        user_state,
+        global_config,
+        scope_price,
+        reward_index as usize,
+        ts,
+    )?;
+
+    let amount_to_stake = if reward_user > 0 {
+        let StakeEffects { amount_to_stake } =
+            farm_operations::compound(farm_state, user_state, scope_price, reward_user, ts)?;
+        amount_to_stake
+    } else {
+        0
+    };
+
+    msg!(
+        "owner {:?} amount_compounded {:?}, amount_treasury {:?}",
+        user_state.owner,
+        amount_to_stake,
+        reward_treasury
+    );
+
+    let farm_state_key = ctx.accounts.farm_state.key();
+
+    let signer_seeds: &[&[&[u8]]] = gen_signer_seeds_two!(
+        BASE_SEED_FARM_VAULTS_AUTHORITY,
+        farm_state_key,
+        farm_state.farm_vaults_authority_bump as u8
+    );
+
+    if amount_to_stake > 0 {
+        token_operations::transfer_2022_from_vault(
+            amount_to_stake,
+            signer_seeds,
+            &ctx.accounts.farm_vault.to_account_info(),
+            &ctx.accounts.rewards_vault.to_account_info(),
+            &ctx.accounts.farm_vaults_authority,
+            &ctx.accounts.token_program,
+            &ctx.accounts.reward_mint.to_account_info(),
+        )?;
+    }
+
+    if reward_treasury > 0 {
+        token_operations::transfer_2022_from_vault(
+            reward_treasury,
+            signer_seeds,
+            &ctx.accounts.rewards_treasury_vault.to_account_info(),
+            &ctx.accounts.rewards_vault.to_account_info(),
+            &ctx.accounts.farm_vaults_authority,
+            &ctx.accounts.token_program,
+            &ctx.accounts.reward_mint.to_account_info(),
+        )?;
+    }
+
+    emit!(CompoundRewardEvent {
+        farm_state: farm_state_key,
+        user_state: ctx.accounts.user_state.key(),
+        owner: user_state.owner,
+        reward_index,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount_compounded: amount_to_stake,
+        reward_treasury,
+        active_stake_scaled_after: user_state.active_stake_scaled,
+        pending_deposit_stake_scaled_after: user_state.pending_deposit_stake_scaled,
+        total_staked_amount: farm_state.total_staked_amount,
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reward_index: u64)]
+pub struct CompoundReward<'info> {
+    #[account(mut,
+        constraint = check_owner_harvesting_permissionless(payer.key(), farm_state.load()?.deref(), user_state.load()?.deref()) @ FarmError::HarvestingNotPermissionlessPayerMismatch,
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_state,
+    )]
+    pub user_state: AccountLoader<'info, UserState>,
+
+    #[account(
+        mut,
+        has_one = global_config,
+        has_one = farm_vaults_authority,
+        has_one = farm_vault,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    #[account(
+        address = farm_state.load()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        mint::token_program = token_program,
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    #[account(mut,
+        seeds = [BASE_SEED_FARM_VAULT, farm_state.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+        constraint = farm_vault.delegate.is_none() @ FarmError::FarmVaultHasDelegate,
+        constraint = farm_vault.close_authority.is_none() @ FarmError::FarmVaultHasCloseAuthority,
+        token::token_program = token_program,
+    )]
+    pub farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    #[account(mut,
+        seeds = [BASE_SEED_REWARD_VAULT, farm_state.key().as_ref(), rewards_vault.mint.as_ref()],
+        bump,
+        constraint = rewards_vault.delegate.is_none() @ FarmError::RewardsVaultHasDelegate,
+        constraint = rewards_vault.close_authority.is_none() @ FarmError::RewardsVaultHasCloseAuthority,
+        constraint = rewards_vault.key() == farm_state.load()?.reward_infos[reward_index as usize].rewards_vault @ FarmError::RewardVaultMismatch,
+        token::mint = reward_mint,
+        token::token_program = token_program
+    )]
+    pub rewards_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    #[account(mut,
+        seeds = [BASE_SEED_REWARD_TREASURY_VAULT.as_ref(), global_config.key().as_ref(), rewards_vault.mint.as_ref()],
+        bump,
+        constraint = rewards_treasury_vault.delegate.is_none() @ FarmError::RewardsTreasuryVaultHasDelegate,
+        constraint = rewards_treasury_vault.close_authority.is_none() @ FarmError::RewardsTreasuryVaultHasCloseAuthority,
+        token::mint = reward_mint,
+        token::token_program = token_program
+    )]
+    pub rewards_treasury_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// CHECK: Verified with a has_one constraint in farm state
+    #[account(
+        seeds = [BASE_SEED_FARM_VAULTS_AUTHORITY, farm_state.key().as_ref()],
+        bump,
+    )]
+    pub farm_vaults_authority: AccountInfo<'info>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::CreateRewardDistributionEvent,
+    farm_operations,
+    state::{ClaimedBitmap, RewardDistribution, TimeUnit},
+    utils::{constraints::check_remaining_accounts, consts::*, scope::load_scope_price},
+    FarmError, FarmState,
+};
+
+pub fn process(
+    ctx: Context<CreateRewardDistribution>,
+    reward_index: u64,
+    distribution_id: u64,
+    merkle_root: [u8; 32],
+    total_amount: u64,
+    num_leaves: u64,
+) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let reward_distribution = &mut ctx.accounts.reward_distribution.load_init()?;
+    ctx.accounts.claimed_bitmap.load_init()?.reward_distribution =
+        ctx.accounts.reward_distribution.key();
+
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+    let ts = TimeUnit::now_from_clock(farm_state.time_unit, &Clock::get()?);
+    msg!(
+        "CreateRewardDistribution farm_state {:?} distribution_id {} reward_index {} ts {}",
+        ctx.accounts.farm_state.key(),
+        distribution_id,
+        reward_index,
+        ts
+    );
+
+    farm_operations::create_reward_distribution(
+        farm_state,
+        reward_distribution,
+        scope_price,
+        ctx.accounts.farm_state.key(),
+        ctx.accounts.claimed_bitmap.key(),
+        distribution_id,
+        reward_index as usize,
+        merkle_root,
+        total_amount,
+        num_leaves,
+        ts,
+    )?;
+
+    emit!(CreateRewardDistributionEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        reward_distribution: ctx.accounts.reward_distribution.key(),
+        distribution_id,
+        reward_index,
+        merkle_root,
+        total_amount,
+        num_leaves,
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reward_index: u64, distribution_id: u64, merkle_root: [u8; 32], total_amount: u64, num_leaves: u64)]
+pub struct CreateRewardDistribution<'info> {
+    #[account(mut)]
+    pub farm_admin: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_admin,
+        constraint = reward_index < farm_state.load()?.num_reward_tokens @ FarmError::RewardIndexOutOfRange,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(init,
+        seeds = [BASE_SEED_REWARD_DISTRIBUTION, farm_state.key().as_ref(), distribution_id.to_le_bytes().as_ref()],
+        bump,
+        payer = farm_admin,
+        space = SIZE_REWARD_DISTRIBUTION,
+    )]
+    pub reward_distribution: AccountLoader<'info, RewardDistribution>,
+
+    #[account(init,
+        seeds = [BASE_SEED_CLAIMED_BITMAP, reward_distribution.key().as_ref()],
+        bump,
+        payer = farm_admin,
+        space = ClaimedBitmap::space(num_leaves),
+    )]
+    pub claimed_bitmap: AccountLoader<'info, ClaimedBitmap>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
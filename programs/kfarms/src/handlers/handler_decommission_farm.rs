@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_2022::Token2022,
+    token_interface::{Mint as MintInterface, TokenAccount as TokenAccountInterface},
+};
+
+use crate::{
+    events::{DecommissionFarmEvent, DecommissionFarmVaultEvent},
+    farm_operations, gen_signer_seeds_two,
+    state::TimeUnit,
+    token_operations,
+    utils::{accessors::mint_transfer_fee, consts::*},
+    FarmError, FarmState,
+};
+
+const ACCOUNTS_PER_VAULT: usize = 3;
+
+pub fn process<'info>(ctx: Context<'_, '_, 'info, 'info, DecommissionFarm<'info>>) -> Result<()> {
+    let farm_state_key = ctx.accounts.farm_state.key();
+    let farm_admin_key = ctx.accounts.farm_admin.key();
+
+    let farm_state = &ctx.accounts.farm_state.load()?;
+    let time_unit = farm_state.time_unit;
+
+    farm_operations::decommission(farm_state)?;
+
+    let mut vaults: Vec<(Pubkey, Pubkey)> = Vec::with_capacity(MAX_REWARDS_TOKENS + 1);
+    if !farm_state.is_delegated() {
+        vaults.push((farm_state.token.mint, farm_state.farm_vault));
+    }
+    vaults.extend(
+        farm_state.reward_infos[..farm_state.num_reward_tokens as usize]
+            .iter()
+            .filter(|reward_info| !reward_info.is_free())
+            .map(|reward_info| (reward_info.token.mint, reward_info.rewards_vault)),
+    );
+
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        vaults.len() * ACCOUNTS_PER_VAULT,
+        FarmError::UnexpectedAccount
+    );
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "DecommissionFarm farm_state {:?} num_vaults {} ts {}",
+        farm_state_key,
+        vaults.len(),
+        ts
+    );
+
+    let epoch = Clock::get()?.epoch;
+    let signer_seeds: &[&[&[u8]]] = gen_signer_seeds_two!(
+        BASE_SEED_FARM_VAULTS_AUTHORITY,
+        farm_state_key,
+        farm_state.farm_vaults_authority_bump as u8
+    );
+
+    for ((expected_mint, expected_vault), vault_accounts) in vaults
+        .iter()
+        .zip(ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_VAULT))
+    {
+        let [mint, vault, admin_token_account] = vault_accounts else {
+            unreachable!()
+        };
+
+        require_keys_eq!(mint.key(), *expected_mint, FarmError::UnexpectedAccount);
+        require_keys_eq!(vault.key(), *expected_vault, FarmError::UnexpectedAccount);
+
+        let _ = InterfaceAccount::<MintInterface>::try_from(mint)?;
+        let vault_account = InterfaceAccount::<TokenAccountInterface>::try_from(vault)?;
+
+        let admin_token_account_data =
+            InterfaceAccount::<TokenAccountInterface>::try_from(admin_token_account)?;
+        require_keys_eq!(
+            admin_token_account_data.owner,
+            farm_admin_key,
+            FarmError::UserTokenAccountOwnerMismatch
+        );
+        require_keys_eq!(
+            admin_token_account_data.mint,
+            *expected_mint,
+            FarmError::UnexpectedAccount
+        );
+
+        let token_program = if *mint.owner == ctx.accounts.token_program.key() {
+            ctx.accounts.token_program.to_account_info()
+        } else {
+            match &ctx.accounts.token_2022_program {
+                Some(token_2022_program) if *mint.owner == token_2022_program.key() => {
+                    token_2022_program.to_account_info()
+                }
+                _ => return err!(FarmError::UnexpectedAccount),
+            }
+        };
+
+        let amount = vault_account.amount;
+        let transfer_fee = mint_transfer_fee(mint, amount, epoch)?;
+
+        msg!(
+            "sweeping vault {:?} mint {:?} amount {} transfer_fee {}",
+            vault.key(),
+            mint.key(),
+            amount,
+            transfer_fee
+        );
+
+        if amount > 0 {
+            token_operations::transfer_2022_from_vault(
+                amount,
+                signer_seeds,
+                admin_token_account,
+                vault,
+                &ctx.accounts.farm_vaults_authority,
+                &token_program,
+                mint,
+            )?;
+        }
+
+        token_operations::harvest_withheld_fees_to_mint(vault, mint, &token_program)?;
+
+        token_operations::close_vault(
+            signer_seeds,
+            vault,
+            &ctx.accounts.farm_admin.to_account_info(),
+            &ctx.accounts.farm_vaults_authority,
+            &token_program,
+        )?;
+
+        emit!(DecommissionFarmVaultEvent {
+            farm_state: farm_state_key,
+            vault: vault.key(),
+            mint: mint.key(),
+            destination: admin_token_account.key(),
+            amount,
+            transfer_fee,
+        });
+    }
+
+    emit!(DecommissionFarmEvent {
+        farm_state: farm_state_key,
+        farm_admin: farm_admin_key,
+        num_vaults_closed: vaults.len() as u64,
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DecommissionFarm<'info> {
+    #[account(mut)]
+    pub farm_admin: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_admin,
+        has_one = farm_vaults_authority,
+        close = farm_admin,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    /// CHECK: Verified with a has_one constraint in farm state
+    #[account(
+        seeds = [BASE_SEED_FARM_VAULTS_AUTHORITY, farm_state.key().as_ref()],
+        bump,
+    )]
+    pub farm_vaults_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+}
@@ -1,7 +1,10 @@
 use anchor_lang::{prelude::*, ToAccountInfo};
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
 
 use crate::{
+    events::DepositToFarmVaultEvent,
     farm_operations,
     token_operations::transfer_from_user,
     utils::{constraints::check_remaining_accounts, consts::*},
@@ -25,8 +28,16 @@ pub fn process(ctx: Context<DepositToFarmVault>, amount: u64) -> Result<()> {
         &ctx.accounts.farm_vault.to_account_info(),
         &ctx.accounts.depositor,
         &ctx.accounts.token_program,
+        &ctx.accounts.token_mint.to_account_info(),
     )?;
 
+    emit!(DepositToFarmVaultEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        total_staked_amount: farm_state.total_staked_amount,
+    });
+
     Ok(())
 }
 
@@ -46,14 +57,22 @@ pub struct DepositToFarmVault<'info> {
         constraint = farm_vault.delegate.is_none() @ FarmError::FarmVaultHasDelegate,
         constraint = farm_vault.close_authority.is_none() @ FarmError::FarmVaultHasCloseAuthority,
         constraint = farm_vault.mint == farm_state.load_mut()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        token::token_program = token_program,
     )]
-    pub farm_vault: Box<Account<'info, TokenAccount>>,
+    pub farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     #[account(mut,
         constraint = depositor_ata.mint == farm_state.load()?.token.mint @ FarmError::UserAtaFarmTokenMintMissmatch,
         token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_ata: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    #[account(
+        address = farm_state.load()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        mint::token_program = token_program,
     )]
-    pub depositor_ata: Box<Account<'info, TokenAccount>>,
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
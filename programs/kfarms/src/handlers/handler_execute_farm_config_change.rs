@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{ConfigChangeExecutedEvent, FarmConfigUpdatedEvent},
+    farm_operations,
+    state::{ConfigChangeTarget, FarmConfigOption, PendingConfigChange, TimeUnit},
+    utils::{constraints::check_remaining_accounts, scope::load_scope_price},
+    FarmError, FarmState,
+};
+
+pub fn process(ctx: Context<ExecuteFarmConfigChange>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let pending_config_change = &ctx.accounts.pending_config_change.load()?;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state).map_or(None, |v| v);
+
+    require!(
+        pending_config_change.target_kind() == ConfigChangeTarget::FarmState,
+        FarmError::ConfigChangeTargetMismatch
+    );
+    require_keys_eq!(
+        pending_config_change.admin,
+        farm_state.farm_admin,
+        FarmError::ConfigChangeAdminMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    farm_operations::check_config_change_executable(pending_config_change, now)?;
+
+    let mode = FarmConfigOption::try_from(pending_config_change.mode)
+        .map_err(|_| FarmError::InvalidConfigValue)?;
+    let data = pending_config_change.data();
+    msg!(
+        "ExecuteFarmConfigChange farm_state {:?} mode {:?} now {}",
+        ctx.accounts.farm_state.key(),
+        mode,
+        now
+    );
+
+    farm_operations::update_farm_config(farm_state, scope_price, mode, data)?;
+
+    emit!(FarmConfigUpdatedEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        signer: pending_config_change.admin,
+        mode: mode.into(),
+        data: data.to_vec(),
+        ts: TimeUnit::now_from_clock(farm_state.time_unit, &Clock::get()?),
+    });
+
+    emit!(ConfigChangeExecutedEvent {
+        pending_config_change: ctx.accounts.pending_config_change.key(),
+        target: ctx.accounts.farm_state.key(),
+        target_kind: ConfigChangeTarget::FarmState as u8,
+        mode: mode.into(),
+        data: data.to_vec(),
+        ts: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteFarmConfigChange<'info> {
+    /// CHECK: Receives the rent, verified with a has_one constraint in pending_config_change
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(mut,
+        has_one = admin,
+        constraint = pending_config_change.load()?.target == farm_state.key() @ FarmError::ConfigChangeTargetMismatch,
+        close = admin,
+    )]
+    pub pending_config_change: AccountLoader<'info, PendingConfigChange>,
+
+    #[account(mut)]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::{ConfigChangeExecutedEvent, GlobalConfigUpdatedEvent},
+    farm_operations,
+    state::{ConfigChangeTarget, PendingConfigChange},
+    utils::{constraints::check_remaining_accounts, consts::PENDING_CONFIG_CHANGE_DATA_LEN},
+    FarmError, GlobalConfig, GlobalConfigOption,
+};
+
+pub fn process(ctx: Context<ExecuteGlobalConfigChange>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let global_config = &mut ctx.accounts.global_config.load_mut()?;
+    let pending_config_change = &ctx.accounts.pending_config_change.load()?;
+
+    require!(
+        pending_config_change.target_kind() == ConfigChangeTarget::GlobalConfig,
+        FarmError::ConfigChangeTargetMismatch
+    );
+    require_keys_eq!(
+        pending_config_change.admin,
+        global_config.global_admin,
+        FarmError::ConfigChangeAdminMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    farm_operations::check_config_change_executable(pending_config_change, now)?;
+
+    let key = u8::try_from(pending_config_change.mode)
+        .ok()
+        .and_then(|mode| GlobalConfigOption::try_from(mode).ok())
+        .ok_or(FarmError::InvalidGlobalConfigMode)?;
+    let value: [u8; PENDING_CONFIG_CHANGE_DATA_LEN] = pending_config_change.data;
+    msg!(
+        "ExecuteGlobalConfigChange global_config {:?} key {:?} now {}",
+        ctx.accounts.global_config.key(),
+        key,
+        now
+    );
+
+    farm_operations::update_global_config(global_config, key, &value)?;
+
+    emit!(GlobalConfigUpdatedEvent {
+        global_config: ctx.accounts.global_config.key(),
+        mode: key as u8,
+        value,
+        pending_global_admin: global_config.pending_global_admin,
+        treasury_fee_bps: global_config.treasury_fee_bps,
+    });
+
+    emit!(ConfigChangeExecutedEvent {
+        pending_config_change: ctx.accounts.pending_config_change.key(),
+        target: ctx.accounts.global_config.key(),
+        target_kind: ConfigChangeTarget::GlobalConfig as u8,
+        mode: pending_config_change.mode,
+        data: value.to_vec(),
+        ts: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteGlobalConfigChange<'info> {
+    /// CHECK: Receives the rent, verified with a has_one constraint in pending_config_change
+    #[account(mut)]
+    pub admin: AccountInfo<'info>,
+
+    #[account(mut,
+        has_one = admin,
+        constraint = pending_config_change.load()?.target == global_config.key() @ FarmError::ConfigChangeTargetMismatch,
+        close = admin,
+    )]
+    pub pending_config_change: AccountLoader<'info, PendingConfigChange>,
+
+    #[account(mut)]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+
+use crate::{
+    farm_operations,
+    state::TimeUnit,
+    utils::{constraints::check_remaining_accounts, scope::load_scope_price},
+    FarmState, GlobalConfig, UserState,
+};
+
+pub fn process(ctx: Context<GetUserPendingRewards>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let mut farm_state = Box::new(*ctx.accounts.farm_state.load()?);
+    let mut user_state = Box::new(*ctx.accounts.user_state.load()?);
+    let global_config = &ctx.accounts.global_config.load()?;
+
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, &farm_state)?;
+    let ts = TimeUnit::now_from_clock(farm_state.time_unit, &Clock::get()?);
+
+    let pending_rewards = farm_operations::get_user_pending_rewards(
+        &mut farm_state,
+        &mut user_state,
+        global_config,
+        scope_price,
+        ts,
+    )?;
+
+    msg!(
+        "GetUserPendingRewards user_state {:?} ts {}",
+        ctx.accounts.user_state.key(),
+        ts
+    );
+
+    set_return_data(&pending_rewards.try_to_vec()?);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetUserPendingRewards<'info> {
+    #[account(
+        has_one = farm_state,
+    )]
+    pub user_state: AccountLoader<'info, UserState>,
+
+    #[account(
+        has_one = global_config,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+}
@@ -0,0 +1,236 @@
+use std::ops::Deref;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
+
+use crate::{
+    events::HarvestEvent,
+    farm_operations, gen_signer_seeds_two,
+    handlers::handler_harvest_reward::check_owner_harvesting_permissionless,
+    state::TimeUnit,
+    token_operations,
+    types::HarvestEffects,
+    utils::{
+        accessors::mint_transfer_fee, constraints::token_2022::validate_reward_token_extensions,
+        consts::*, scope::load_scope_price,
+    },
+    FarmError, FarmState, GlobalConfig, UserState,
+};
+
+const ACCOUNTS_PER_REWARD: usize = 5;
+
+pub fn process<'info>(ctx: Context<'_, '_, 'info, 'info, HarvestAllRewards<'info>>) -> Result<()> {
+    let farm_state_key = ctx.accounts.farm_state.key();
+    let user_state_key = ctx.accounts.user_state.key();
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let time_unit = farm_state.time_unit;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+
+    let user_state = &mut ctx.accounts.user_state.load_mut()?;
+    let global_config = &ctx.accounts.global_config.load()?;
+
+    let reward_indices: Vec<usize> = (0..farm_state.num_reward_tokens as usize)
+        .filter(|reward_index| !farm_state.reward_infos[*reward_index].is_free())
+        .collect();
+
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        reward_indices.len() * ACCOUNTS_PER_REWARD,
+        FarmError::UnexpectedAccount
+    );
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "HarvestAllRewards user_state {:?}, farm_state {:?} ts {}",
+        user_state_key,
+        farm_state_key,
+        ts
+    );
+
+    let mut effects =
+        farm_operations::harvest_all(farm_state, user_state, global_config, scope_price, ts)?;
+
+    let epoch = Clock::get()?.epoch;
+    let signer_seeds: &[&[&[u8]]] = gen_signer_seeds_two!(
+        BASE_SEED_FARM_VAULTS_AUTHORITY,
+        farm_state_key,
+        farm_state.farm_vaults_authority_bump as u8
+    );
+
+    for (reward_index, reward_accounts) in reward_indices
+        .into_iter()
+        .zip(ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_REWARD))
+    {
+        let reward_info = &farm_state.reward_infos[reward_index];
+        let reward_effects = effects[reward_index].take();
+        let [reward_mint, rewards_vault, rewards_treasury_vault, user_reward_token_account, token_program] =
+            reward_accounts
+        else {
+            unreachable!()
+        };
+
+        require_keys_eq!(
+            reward_mint.key(),
+            reward_info.token.mint,
+            FarmError::RewardAtaRewardMintMissmatch
+        );
+        require_keys_eq!(
+            rewards_vault.key(),
+            reward_info.rewards_vault,
+            FarmError::RewardVaultMismatch
+        );
+        let token_program = Interface::<TokenInterface>::try_from(token_program)?;
+        require_keys_eq!(
+            *reward_mint.owner,
+            token_program.key(),
+            FarmError::UnexpectedAccount
+        );
+
+        let _ = InterfaceAccount::<MintInterface>::try_from(reward_mint)?;
+        validate_reward_token_extensions(reward_mint)?;
+
+        let rewards_vault_account =
+            InterfaceAccount::<TokenAccountInterface>::try_from(rewards_vault)?;
+        require!(
+            rewards_vault_account.delegate.is_none(),
+            FarmError::RewardsVaultHasDelegate
+        );
+        require!(
+            rewards_vault_account.close_authority.is_none(),
+            FarmError::RewardsVaultHasCloseAuthority
+        );
+
+        let rewards_treasury_vault_account =
+            InterfaceAccount::<TokenAccountInterface>::try_from(rewards_treasury_vault)?;
+        require_keys_eq!(
+            rewards_treasury_vault_account.owner,
+            global_config.treasury_vaults_authority,
+            FarmError::WrongRewardVaultAccount
+        );
+        require_keys_eq!(
+            rewards_treasury_vault_account.mint,
+            reward_info.token.mint,
+            FarmError::WrongRewardVaultAccount
+        );
+        require!(
+            rewards_treasury_vault_account.delegate.is_none(),
+            FarmError::RewardsTreasuryVaultHasDelegate
+        );
+        require!(
+            rewards_treasury_vault_account.close_authority.is_none(),
+            FarmError::RewardsTreasuryVaultHasCloseAuthority
+        );
+
+        let user_reward_token_account_data =
+            InterfaceAccount::<TokenAccountInterface>::try_from(user_reward_token_account)?;
+        require_keys_eq!(
+            user_reward_token_account_data.owner,
+            user_state.owner,
+            FarmError::UserTokenAccountOwnerMismatch
+        );
+        require_keys_eq!(
+            user_reward_token_account_data.mint,
+            reward_info.token.mint,
+            FarmError::UserAtaRewardVaultMintMissmatch
+        );
+
+        let Some(HarvestEffects {
+            reward_user,
+            reward_treasury,
+            reward_vested,
+        }) = reward_effects
+        else {
+            continue;
+        };
+
+        let reward_user_transfer_fee = mint_transfer_fee(reward_mint, reward_user, epoch)?;
+        let reward_treasury_transfer_fee = mint_transfer_fee(reward_mint, reward_treasury, epoch)?;
+
+        msg!(
+            "reward_index {} owner {:?} amount_user {:?}, amount_treasury {:?}, transfer_fee_user {:?}, transfer_fee_treasury {:?}",
+            reward_index,
+            user_state.owner,
+            reward_user,
+            reward_treasury,
+            reward_user_transfer_fee,
+            reward_treasury_transfer_fee
+        );
+
+        if reward_user > 0 {
+            token_operations::transfer_2022_from_vault(
+                reward_user,
+                signer_seeds,
+                user_reward_token_account,
+                rewards_vault,
+                &ctx.accounts.farm_vaults_authority,
+                &token_program.to_account_info(),
+                reward_mint,
+            )?;
+        }
+
+        if reward_treasury > 0 {
+            token_operations::transfer_2022_from_vault(
+                reward_treasury,
+                signer_seeds,
+                rewards_treasury_vault,
+                rewards_vault,
+                &ctx.accounts.farm_vaults_authority,
+                &token_program.to_account_info(),
+                reward_mint,
+            )?;
+        }
+
+        emit!(HarvestEvent {
+            farm_state: farm_state_key,
+            user_state: user_state_key,
+            owner: user_state.owner,
+            reward_index: reward_index as u64,
+            reward_mint: reward_info.token.mint,
+            reward_user,
+            reward_treasury,
+            reward_user_transfer_fee,
+            reward_treasury_transfer_fee,
+            reward_vested,
+            reward_per_share_scaled: reward_info.reward_per_share_scaled,
+            rewards_issued_unclaimed: reward_info.rewards_issued_unclaimed,
+            ts,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct HarvestAllRewards<'info> {
+    #[account(mut,
+        constraint = check_owner_harvesting_permissionless(payer.key(), farm_state.load()?.deref(), user_state.load()?.deref()) @ FarmError::HarvestingNotPermissionlessPayerMismatch,
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_state,
+    )]
+    pub user_state: AccountLoader<'info, UserState>,
+
+    #[account(
+        mut,
+        has_one = global_config,
+        has_one = farm_vaults_authority
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// CHECK: Verified with a has_one constraint in farm state
+    #[account(
+        seeds = [BASE_SEED_FARM_VAULTS_AUTHORITY, farm_state.key().as_ref()],
+        bump,
+    )]
+    pub farm_vaults_authority: AccountInfo<'info>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+}
@@ -1,16 +1,18 @@
 use std::ops::Deref;
 
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
 use anchor_spl::token_interface::{
     Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
 };
 
 use crate::{
+    events::HarvestEvent,
     farm_operations, gen_signer_seeds_two,
     state::TimeUnit,
     token_operations,
-    types::HarvestEffects,
+    types::{HarvestEffects, HarvestReturnData},
     utils::{
+        accessors::mint_transfer_fee,
         constraints::{check_remaining_accounts, token_2022::validate_reward_token_extensions},
         consts::*,
         scope::load_scope_price,
@@ -34,29 +36,39 @@ pub fn process(ctx: Context<HarvestReward>, reward_index: u64) -> Result<()> {
         FarmError::RewardIndexOutOfRange
     );
 
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
     msg!(
         "HarvestReward user_state {:?}, farm_state {:?} ts {}",
         ctx.accounts.user_state.key(),
         ctx.accounts.farm_state.key(),
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?)
+        ts
     );
     let HarvestEffects {
         reward_user,
         reward_treasury,
+        reward_vested,
     } = farm_operations::harvest(
         farm_state,
         user_state,
         global_config,
         scope_price,
         reward_index as usize,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
+        ts,
     )?;
 
+    let epoch = Clock::get()?.epoch;
+    let reward_mint_info = ctx.accounts.reward_mint.to_account_info();
+    let reward_user_transfer_fee = mint_transfer_fee(&reward_mint_info, reward_user, epoch)?;
+    let reward_treasury_transfer_fee =
+        mint_transfer_fee(&reward_mint_info, reward_treasury, epoch)?;
+
     msg!(
-        "owner {:?} amount_user {:?}, amount_treasury {:?}",
+        "owner {:?} amount_user {:?}, amount_treasury {:?}, transfer_fee_user {:?}, transfer_fee_treasury {:?}",
         user_state.owner,
         reward_user,
-        reward_treasury
+        reward_treasury,
+        reward_user_transfer_fee,
+        reward_treasury_transfer_fee
     );
 
     let farm_state_key = ctx.accounts.farm_state.key();
@@ -91,6 +103,35 @@ pub fn process(ctx: Context<HarvestReward>, reward_index: u64) -> Result<()> {
         )?;
     }
 
+    let reward_info = &farm_state.reward_infos[reward_index as usize];
+    emit!(HarvestEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        user_state: ctx.accounts.user_state.key(),
+        owner: user_state.owner,
+        reward_index,
+        reward_mint: reward_info.token.mint,
+        reward_user,
+        reward_treasury,
+        reward_user_transfer_fee,
+        reward_treasury_transfer_fee,
+        reward_vested,
+        reward_per_share_scaled: reward_info.reward_per_share_scaled,
+        rewards_issued_unclaimed: reward_info.rewards_issued_unclaimed,
+        ts,
+    });
+
+    set_return_data(
+        &HarvestReturnData {
+            effects: HarvestEffects {
+                reward_user,
+                reward_treasury,
+                reward_vested,
+            },
+            user_stake: (&**user_state).into(),
+        }
+        .try_to_vec()?,
+    );
+
     Ok(())
 }
 
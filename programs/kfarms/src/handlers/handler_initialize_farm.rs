@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
 
 use crate::{
+    events::InitializeFarmEvent,
     state::{GlobalConfig, RewardInfo, TimeUnit, TokenInfo},
-    utils::{constraints::check_remaining_accounts, consts::*},
+    utils::{
+        constraints::{check_remaining_accounts, token_2022::validate_base_token_extensions},
+        consts::*,
+    },
     FarmState,
 };
 
@@ -11,31 +17,52 @@ pub fn process(ctx: Context<InitializeFarm>) -> Result<()> {
     check_remaining_accounts(&ctx)?;
 
     let mut farm_state = ctx.accounts.farm_state.load_init()?;
+    initialize(&mut farm_state, ctx.accounts, &ctx.bumps)
+}
+
+pub(crate) fn initialize(
+    farm_state: &mut FarmState,
+    accounts: &InitializeFarm,
+    bumps: &InitializeFarmBumps,
+) -> Result<()> {
+    validate_base_token_extensions(&accounts.token_mint.to_account_info())?;
 
-    farm_state.farm_admin = ctx.accounts.farm_admin.key();
-    farm_state.pending_farm_admin = ctx.accounts.farm_admin.key();
-    farm_state.global_config = ctx.accounts.global_config.key();
-    farm_state.farm_vaults_authority = ctx.accounts.farm_vaults_authority.key();
-    farm_state.farm_vaults_authority_bump = ctx.bumps.farm_vaults_authority.into();
+    farm_state.farm_admin = accounts.farm_admin.key();
+    farm_state.pending_farm_admin = accounts.farm_admin.key();
+    farm_state.global_config = accounts.global_config.key();
+    farm_state.farm_vaults_authority = accounts.farm_vaults_authority.key();
+    farm_state.farm_vaults_authority_bump = bumps.farm_vaults_authority.into();
     farm_state.reward_infos = [RewardInfo::default(); 10];
     farm_state.scope_oracle_price_id = u64::MAX;
 
    
     farm_state.token = TokenInfo {
-        mint: ctx.accounts.token_mint.key(),
-        decimals: ctx.accounts.token_mint.decimals as u64,
-        token_program: ctx.accounts.token_program.key(),
+        mint: accounts.token_mint.key(),
+        decimals: accounts.token_mint.decimals as u64,
+        token_program: accounts.token_program.key(),
         _padding: [0; 6],
     };
-    farm_state.farm_vault = ctx.accounts.farm_vault.key();
+    farm_state.farm_vault = accounts.farm_vault.key();
     farm_state.delegate_authority = Pubkey::default();
     farm_state.second_delegated_authority = Pubkey::default();
+    let ts = TimeUnit::now_from_clock(farm_state.time_unit, &Clock::get()?);
     msg!(
         "Initialize farm {:?} ts {}",
-        ctx.accounts.farm_state.to_account_info().key(),
-        TimeUnit::now_from_clock(farm_state.time_unit, &Clock::get()?)
+        accounts.farm_state.to_account_info().key(),
+        ts
     );
 
+    emit!(InitializeFarmEvent {
+        farm_state: accounts.farm_state.key(),
+        farm_admin: farm_state.farm_admin,
+        global_config: farm_state.global_config,
+        token_mint: farm_state.token.mint,
+        token_program: farm_state.token.token_program,
+        farm_vault: farm_state.farm_vault,
+        delegate_authority: farm_state.delegate_authority,
+        ts,
+    });
+
     Ok(())
 }
 
@@ -55,8 +82,9 @@ pub struct InitializeFarm<'info> {
         bump,
         token::mint = token_mint,
         token::authority = farm_vaults_authority,
+        token::token_program = token_program,
     )]
-    pub farm_vault: Box<Account<'info, TokenAccount>>,
+    pub farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     /// CHECK: authority
     #[account(
@@ -65,9 +93,12 @@ pub struct InitializeFarm<'info> {
     )]
     pub farm_vaults_authority: AccountInfo<'info>,
 
-    pub token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 
     pub rent: Sysvar<'info, Rent>,
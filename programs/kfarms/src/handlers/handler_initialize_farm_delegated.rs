@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    events::InitializeFarmEvent,
     state::{GlobalConfig, RewardInfo, TimeUnit, TokenInfo},
     utils::{constraints::check_remaining_accounts, consts::*},
     FarmState,
@@ -26,12 +27,24 @@ pub fn process(ctx: Context<InitializeFarmDelegated>) -> Result<()> {
     farm_state.delegate_authority = ctx.accounts.farm_delegate.key();
     farm_state.is_farm_delegated = true as u8;
 
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
     msg!(
         "InitializeFarmDelegated {:?} ts {}",
         ctx.accounts.farm_state.to_account_info().key(),
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?)
+        ts
     );
 
+    emit!(InitializeFarmEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        farm_admin: farm_state.farm_admin,
+        global_config: farm_state.global_config,
+        token_mint: farm_state.token.mint,
+        token_program: farm_state.token.token_program,
+        farm_vault: farm_state.farm_vault,
+        delegate_authority: farm_state.delegate_authority,
+        ts,
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+use crate::{
+    events::InitializeReceiptMintEvent,
+    farm_operations,
+    handlers::handler_initialize_farm::{self, *},
+    utils::{constraints::check_remaining_accounts, consts::*},
+};
+
+pub fn process(ctx: Context<InitializeFarmWithReceiptMint>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state_key = ctx.accounts.initialize_farm.farm_state.key();
+    let mut farm_state = ctx.accounts.initialize_farm.farm_state.load_init()?;
+
+    handler_initialize_farm::initialize(
+        &mut farm_state,
+        &ctx.accounts.initialize_farm,
+        &ctx.bumps.initialize_farm,
+    )?;
+
+    msg!(
+        "InitializeFarmWithReceiptMint farm_state {:?} receipt_mint {:?}",
+        farm_state_key,
+        ctx.accounts.receipt_mint.key()
+    );
+
+    farm_operations::initialize_receipt_mint(&mut farm_state, ctx.accounts.receipt_mint.key())?;
+
+    emit!(InitializeReceiptMintEvent {
+        farm_state: farm_state_key,
+        receipt_mint: ctx.accounts.receipt_mint.key(),
+        decimals: ctx.accounts.receipt_mint.decimals,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeFarmWithReceiptMint<'info> {
+    pub initialize_farm: InitializeFarm<'info>,
+
+    #[account(init,
+        payer = initialize_farm.farm_admin,
+        seeds = [BASE_SEED_RECEIPT_MINT, initialize_farm.farm_state.key().as_ref()],
+        bump,
+        mint::decimals = initialize_farm.token_mint.decimals,
+        mint::authority = initialize_farm.farm_vaults_authority,
+        mint::token_program = receipt_token_program,
+    )]
+    pub receipt_mint: Box<Account<'info, Mint>>,
+
+    pub receipt_token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    events::InitializeGlobalConfigEvent,
     state::GlobalConfig,
     utils::{constraints::check_remaining_accounts, consts::BASE_SEED_TREASURY_VAULTS_AUTHORITY},
 };
@@ -14,6 +15,12 @@ pub fn process(ctx: Context<InitializeGlobalConfig>) -> Result<()> {
     global_config.treasury_vaults_authority = ctx.accounts.treasury_vaults_authority.key();
     global_config.treasury_vaults_authority_bump = ctx.bumps.treasury_vaults_authority.into();
 
+    emit!(InitializeGlobalConfigEvent {
+        global_config: ctx.accounts.global_config.key(),
+        global_admin: global_config.global_admin,
+        treasury_vaults_authority: global_config.treasury_vaults_authority,
+    });
+
     Ok(())
 }
 
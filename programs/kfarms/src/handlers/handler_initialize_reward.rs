@@ -4,6 +4,7 @@ use anchor_spl::token_interface::{
 };
 
 use crate::{
+    events::InitializeRewardEvent,
     farm_operations,
     state::{GlobalConfig, TimeUnit},
     utils::{
@@ -20,14 +21,15 @@ pub fn process(ctx: Context<InitializeReward>) -> Result<()> {
     let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
     let time_unit = farm_state.time_unit;
     let reward_mint = &mut ctx.accounts.reward_mint;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
 
-    farm_operations::initialize_reward(
+    let reward_index = farm_operations::initialize_reward(
         farm_state,
         ctx.accounts.reward_vault.key(),
         reward_mint.key(),
         reward_mint.decimals,
         ctx.accounts.token_program.key(),
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
+        ts,
     )?;
 
    
@@ -35,9 +37,18 @@ pub fn process(ctx: Context<InitializeReward>) -> Result<()> {
         "InitializeReward {:?} farm_state {:?} ts {}",
         ctx.accounts.reward_mint.key(),
         ctx.accounts.farm_state.key(),
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?)
+        ts
     );
 
+    emit!(InitializeRewardEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        reward_index: reward_index as u64,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        reward_vault: ctx.accounts.reward_vault.key(),
+        token_program: ctx.accounts.token_program.key(),
+        ts,
+    });
+
     Ok(())
 }
 
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    events::InitializeUserEvent,
     farm_operations,
     state::TimeUnit,
     utils::{constraints::check_remaining_accounts, consts::*},
@@ -16,12 +17,13 @@ pub fn process(ctx: Context<InitializeUser>) -> Result<()> {
     let payer = ctx.accounts.payer.key();
     let owner = ctx.accounts.owner.key();
     let user_state_bump = ctx.bumps.user_state.into();
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
 
     msg!(
         "InitializeUser: user {} farm {} ts {}",
         ctx.accounts.user_state.key(),
         ctx.accounts.farm_state.key(),
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?)
+        ts
     );
 
     if !farm_state.is_delegated() {
@@ -56,9 +58,18 @@ pub fn process(ctx: Context<InitializeUser>) -> Result<()> {
         user_state,
         &owner,
         &ctx.accounts.farm_state.key(),
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
+        ts,
     )?;
 
+    emit!(InitializeUserEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        user_state: ctx.accounts.user_state.key(),
+        owner,
+        delegatee: user_state.delegatee,
+        user_id: user_state.user_id,
+        ts,
+    });
+
     Ok(())
 }
 
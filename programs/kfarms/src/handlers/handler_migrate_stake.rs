@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
+
+use crate::{
+    events::MigrateStakeEvent,
+    farm_operations, gen_signer_seeds_two,
+    state::TimeUnit,
+    token_operations,
+    utils::{
+        accessors::mint_transfer_fee, constraints::check_remaining_accounts, consts::*,
+        scope::load_scope_price,
+    },
+    FarmError, FarmState, UserState,
+};
+
+pub fn process(ctx: Context<MigrateStake>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let source_farm_state_key = ctx.accounts.source_farm_state.key();
+    let destination_farm_state_key = ctx.accounts.destination_farm_state.key();
+
+    let source_farm_state = &mut ctx.accounts.source_farm_state.load_mut()?;
+    let source_user_state = &mut ctx.accounts.source_user_state.load_mut()?;
+    let destination_farm_state = &mut ctx.accounts.destination_farm_state.load_mut()?;
+    let destination_user_state = &mut ctx.accounts.destination_user_state.load_mut()?;
+
+    require!(
+        !destination_farm_state.is_delegated(),
+        FarmError::FarmDelegated
+    );
+
+    let source_scope_price =
+        load_scope_price(&ctx.accounts.source_scope_prices, source_farm_state)?;
+    let destination_scope_price = load_scope_price(
+        &ctx.accounts.destination_scope_prices,
+        destination_farm_state,
+    )?;
+
+    let clock = Clock::get()?;
+    let source_ts = TimeUnit::now_from_clock(source_farm_state.time_unit, &clock);
+    let destination_ts = TimeUnit::now_from_clock(destination_farm_state.time_unit, &clock);
+
+    msg!(
+        "MigrateStake source_farm_state {:?} destination_farm_state {:?} ts {}",
+        source_farm_state_key,
+        destination_farm_state_key,
+        source_ts
+    );
+
+    let amount = farm_operations::migrate_stake_out(
+        source_farm_state,
+        source_user_state,
+        source_scope_price,
+        &destination_farm_state_key,
+        source_ts,
+    )?;
+
+    let transfer_fee = mint_transfer_fee(
+        &ctx.accounts.token_mint.to_account_info(),
+        amount,
+        clock.epoch,
+    )?;
+    let amount_staked = amount - transfer_fee;
+
+    farm_operations::stake_with_cap_check(
+        destination_farm_state,
+        destination_user_state,
+        destination_scope_price,
+        amount_staked,
+        destination_ts,
+        true,
+        true,
+    )?;
+
+    msg!(
+        "migrating amount {} transfer_fee {} amount_staked {}",
+        amount,
+        transfer_fee,
+        amount_staked
+    );
+
+    let signer_seeds: &[&[&[u8]]] = gen_signer_seeds_two!(
+        BASE_SEED_FARM_VAULTS_AUTHORITY,
+        source_farm_state_key,
+        source_farm_state.farm_vaults_authority_bump as u8
+    );
+
+    token_operations::transfer_2022_from_vault(
+        amount,
+        signer_seeds,
+        &ctx.accounts.destination_farm_vault.to_account_info(),
+        &ctx.accounts.source_farm_vault.to_account_info(),
+        &ctx.accounts.source_farm_vaults_authority,
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint.to_account_info(),
+    )?;
+
+    emit!(MigrateStakeEvent {
+        source_farm_state: source_farm_state_key,
+        source_user_state: ctx.accounts.source_user_state.key(),
+        destination_farm_state: destination_farm_state_key,
+        destination_user_state: ctx.accounts.destination_user_state.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+        transfer_fee,
+        amount_staked,
+        ts: source_ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateStake<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub source_farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(mut,
+        has_one = owner,
+        constraint = source_user_state.load()?.farm_state == source_farm_state.key() @ FarmError::UnexpectedAccount,
+    )]
+    pub source_user_state: AccountLoader<'info, UserState>,
+
+    #[account(mut,
+        constraint = source_farm_vault.key() == source_farm_state.load()?.farm_vault @ FarmError::UnexpectedAccount,
+        constraint = source_farm_vault.delegate.is_none() @ FarmError::FarmVaultHasDelegate,
+        constraint = source_farm_vault.close_authority.is_none() @ FarmError::FarmVaultHasCloseAuthority,
+        token::token_program = token_program,
+    )]
+    pub source_farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// CHECK: Verified against the source farm state
+    #[account(
+        seeds = [BASE_SEED_FARM_VAULTS_AUTHORITY, source_farm_state.key().as_ref()],
+        bump,
+        constraint = source_farm_vaults_authority.key() == source_farm_state.load()?.farm_vaults_authority @ FarmError::UnexpectedAccount,
+    )]
+    pub source_farm_vaults_authority: AccountInfo<'info>,
+
+    #[account(mut,
+        constraint = destination_farm_state.load()?.token.mint == source_farm_state.load()?.token.mint @ FarmError::MigrationMintMismatch,
+    )]
+    pub destination_farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(mut,
+        has_one = owner,
+        constraint = destination_user_state.load()?.farm_state == destination_farm_state.key() @ FarmError::UnexpectedAccount,
+    )]
+    pub destination_user_state: AccountLoader<'info, UserState>,
+
+    #[account(mut,
+        constraint = destination_farm_vault.key() == destination_farm_state.load()?.farm_vault @ FarmError::UnexpectedAccount,
+        token::authority = destination_farm_state.load()?.farm_vaults_authority,
+        token::token_program = token_program,
+    )]
+    pub destination_farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    #[account(
+        address = source_farm_state.load()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// CHECK: Farm checks this
+    pub source_scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+
+    /// CHECK: Farm checks this
+    pub destination_scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChangeQueuedEvent,
+    farm_operations,
+    state::{ConfigChangeTarget, FarmConfigOption, PendingConfigChange},
+    utils::{constraints::check_remaining_accounts, consts::*},
+    FarmError, FarmState,
+};
+
+pub fn process(ctx: Context<QueueFarmConfigChange>, mode: u16, data: &[u8]) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &ctx.accounts.farm_state.load()?;
+    let pending_config_change = &mut ctx.accounts.pending_config_change.load_init()?;
+
+    FarmConfigOption::try_from(mode).map_err(|_| FarmError::InvalidConfigValue)?;
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    msg!(
+        "QueueFarmConfigChange farm_state {:?} mode {} timelock {} now {}",
+        ctx.accounts.farm_state.key(),
+        mode,
+        farm_state.config_timelock_seconds,
+        now
+    );
+
+    farm_operations::queue_config_change(
+        pending_config_change,
+        ctx.accounts.farm_state.key(),
+        ConfigChangeTarget::FarmState,
+        ctx.accounts.farm_admin.key(),
+        mode,
+        data,
+        farm_state.config_timelock_seconds,
+        now,
+    )?;
+
+    emit!(ConfigChangeQueuedEvent {
+        pending_config_change: ctx.accounts.pending_config_change.key(),
+        target: ctx.accounts.farm_state.key(),
+        target_kind: ConfigChangeTarget::FarmState as u8,
+        admin: ctx.accounts.farm_admin.key(),
+        mode,
+        data: data.to_vec(),
+        execute_after_ts: pending_config_change.execute_after_ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(mode: u16)]
+pub struct QueueFarmConfigChange<'info> {
+    #[account(mut)]
+    pub farm_admin: Signer<'info>,
+
+    #[account(
+        has_one = farm_admin,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(init,
+        seeds = [BASE_SEED_PENDING_CONFIG_CHANGE, farm_state.key().as_ref(), mode.to_le_bytes().as_ref()],
+        bump,
+        payer = farm_admin,
+        space = SIZE_PENDING_CONFIG_CHANGE,
+    )]
+    pub pending_config_change: AccountLoader<'info, PendingConfigChange>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::ConfigChangeQueuedEvent,
+    farm_operations,
+    state::{ConfigChangeTarget, PendingConfigChange},
+    utils::{constraints::check_remaining_accounts, consts::*},
+    GlobalConfig, GlobalConfigOption,
+};
+
+pub fn process(
+    ctx: Context<QueueGlobalConfigChange>,
+    key: GlobalConfigOption,
+    value: &[u8; PENDING_CONFIG_CHANGE_DATA_LEN],
+) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let global_config = &ctx.accounts.global_config.load()?;
+    let pending_config_change = &mut ctx.accounts.pending_config_change.load_init()?;
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    msg!(
+        "QueueGlobalConfigChange global_config {:?} key {:?} timelock {} now {}",
+        ctx.accounts.global_config.key(),
+        key,
+        global_config.config_timelock_seconds,
+        now
+    );
+
+    farm_operations::queue_config_change(
+        pending_config_change,
+        ctx.accounts.global_config.key(),
+        ConfigChangeTarget::GlobalConfig,
+        ctx.accounts.global_admin.key(),
+        key as u16,
+        value,
+        global_config.config_timelock_seconds,
+        now,
+    )?;
+
+    emit!(ConfigChangeQueuedEvent {
+        pending_config_change: ctx.accounts.pending_config_change.key(),
+        target: ctx.accounts.global_config.key(),
+        target_kind: ConfigChangeTarget::GlobalConfig as u8,
+        admin: ctx.accounts.global_admin.key(),
+        mode: key as u16,
+        data: value.to_vec(),
+        execute_after_ts: pending_config_change.execute_after_ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(mode: u8)]
+pub struct QueueGlobalConfigChange<'info> {
+    #[account(mut)]
+    pub global_admin: Signer<'info>,
+
+    #[account(
+        has_one = global_admin,
+    )]
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    #[account(init,
+        seeds = [BASE_SEED_PENDING_CONFIG_CHANGE, global_config.key().as_ref(), u16::from(mode).to_le_bytes().as_ref()],
+        bump,
+        payer = global_admin,
+        space = SIZE_PENDING_CONFIG_CHANGE,
+    )]
+    pub pending_config_change: AccountLoader<'info, PendingConfigChange>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
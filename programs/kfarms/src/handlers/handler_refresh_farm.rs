@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    events::RefreshFarmEvent,
     farm_operations,
     state::TimeUnit,
     utils::{constraints::check_remaining_accounts, scope::load_scope_price},
@@ -22,11 +23,20 @@ pub fn process(ctx: Context<RefreshFarm>) -> Result<()> {
         farm_state.token.token_program = Pubkey::default();
     }
 
-    farm_operations::refresh_global_rewards(
-        farm_state,
-        scope_price,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
-    )?;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    farm_operations::refresh_global_rewards(farm_state, scope_price, ts)?;
+
+    let reward_infos = &farm_state.reward_infos[..farm_state.num_reward_tokens as usize];
+    emit!(RefreshFarmEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        rewards_per_share_scaled: reward_infos
+            .iter()
+            .map(|r| r.reward_per_share_scaled)
+            .collect(),
+        rewards_available: reward_infos.iter().map(|r| r.rewards_available).collect(),
+        ts,
+    });
+
     Ok(())
 }
 
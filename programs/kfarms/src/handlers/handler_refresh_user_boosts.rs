@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::RefreshUserBoostEvent, farm_operations, state::TimeUnit,
+    utils::scope::load_scope_price, FarmError, FarmState, UserState,
+};
+
+pub fn process<'info>(ctx: Context<'_, '_, 'info, 'info, RefreshUserBoosts<'info>>) -> Result<()> {
+    let farm_state_key = ctx.accounts.farm_state.key();
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let time_unit = farm_state.time_unit;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+
+    require!(!farm_state.is_delegated(), FarmError::FarmDelegated);
+    require!(farm_state.is_boost_enabled(), FarmError::BoostNotEnabled);
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "RefreshUserBoosts: num_users:{} ts:{}",
+        ctx.remaining_accounts.len(),
+        ts
+    );
+
+    for user_state_info in ctx.remaining_accounts.iter() {
+        let user_state_loader = AccountLoader::<UserState>::try_from(user_state_info)?;
+        let user_state = &mut user_state_loader.load_mut()?;
+        require_keys_eq!(
+            user_state.farm_state,
+            farm_state_key,
+            FarmError::UnexpectedAccount
+        );
+
+        farm_operations::user_refresh_state(farm_state, user_state, scope_price, ts)?;
+
+        emit!(RefreshUserBoostEvent {
+            farm_state: farm_state_key,
+            user_state: user_state_info.key(),
+            lock_end_ts: user_state.lock_end_ts,
+            boosted_stake_scaled: user_state.boosted_stake_scaled,
+            total_boosted_stake_scaled: farm_state.total_boosted_stake_scaled,
+            ts,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefreshUserBoosts<'info> {
+    #[account(mut)]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    events::RefreshUserStateEvent,
     farm_operations,
     state::TimeUnit,
     utils::{constraints::check_remaining_accounts, scope::load_scope_price},
@@ -15,12 +16,19 @@ pub fn process(ctx: Context<RefreshUserState>) -> Result<()> {
     let time_unit = farm_state.time_unit;
     let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
 
-    farm_operations::user_refresh_state(
-        farm_state,
-        user_state,
-        scope_price,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
-    )?;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    farm_operations::user_refresh_state(farm_state, user_state, scope_price, ts)?;
+
+    emit!(RefreshUserStateEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        user_state: ctx.accounts.user_state.key(),
+        active_stake_scaled: user_state.active_stake_scaled,
+        pending_deposit_stake_scaled: user_state.pending_deposit_stake_scaled,
+        rewards_issued_unclaimed: user_state.rewards_issued_unclaimed
+            [..farm_state.num_reward_tokens as usize]
+            .to_vec(),
+        ts,
+    });
 
     Ok(())
 }
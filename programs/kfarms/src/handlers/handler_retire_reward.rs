@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
+
+use crate::{
+    events::RetireRewardEvent,
+    farm_operations, gen_signer_seeds_two,
+    state::TimeUnit,
+    token_operations,
+    types::RetireRewardEffects,
+    utils::{
+        accessors::mint_transfer_fee, constraints::check_remaining_accounts,
+        consts::BASE_SEED_FARM_VAULTS_AUTHORITY, scope::load_scope_price,
+    },
+    FarmError, FarmState,
+};
+
+pub fn process(ctx: Context<RetireReward>, reward_index: u64, grace_period: u64) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state_key = ctx.accounts.farm_state.key();
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let time_unit = farm_state.time_unit;
+    let reward_mint = ctx.accounts.reward_mint.key();
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "RetireReward farm_state {:?} reward_index {} grace_period {} ts {}",
+        farm_state_key,
+        reward_index,
+        grace_period,
+        ts
+    );
+
+    let RetireRewardEffects {
+        reward_amount,
+        slot_freed,
+    } = farm_operations::retire_reward(
+        farm_state,
+        scope_price,
+        &reward_mint,
+        reward_index as usize,
+        grace_period,
+        ts,
+    )?;
+
+    let signer_seeds: &[&[&[u8]]] = gen_signer_seeds_two!(
+        BASE_SEED_FARM_VAULTS_AUTHORITY,
+        farm_state_key,
+        farm_state.farm_vaults_authority_bump as u8
+    );
+
+    let vault_swept_amount = if slot_freed {
+        ctx.accounts.reward_vault.amount
+    } else {
+        reward_amount
+    };
+
+    let transfer_fee = mint_transfer_fee(
+        &ctx.accounts.reward_mint.to_account_info(),
+        vault_swept_amount,
+        Clock::get()?.epoch,
+    )?;
+    msg!(
+        "returning {} rewards_available, sweeping {} transfer fee {} slot_freed {}",
+        reward_amount,
+        vault_swept_amount,
+        transfer_fee,
+        slot_freed
+    );
+
+    if vault_swept_amount > 0 {
+        token_operations::transfer_2022_from_vault(
+            vault_swept_amount,
+            signer_seeds,
+            &ctx.accounts.admin_reward_token_ata.to_account_info(),
+            &ctx.accounts.reward_vault.to_account_info(),
+            &ctx.accounts.farm_vaults_authority,
+            &ctx.accounts.token_program,
+            &ctx.accounts.reward_mint.to_account_info(),
+        )?;
+    }
+
+    if slot_freed {
+        token_operations::harvest_withheld_fees_to_mint(
+            &ctx.accounts.reward_vault.to_account_info(),
+            &ctx.accounts.reward_mint.to_account_info(),
+            &ctx.accounts.token_program,
+        )?;
+        token_operations::close_vault(
+            signer_seeds,
+            &ctx.accounts.reward_vault.to_account_info(),
+            &ctx.accounts.farm_admin.to_account_info(),
+            &ctx.accounts.farm_vaults_authority,
+            &ctx.accounts.token_program,
+        )?;
+    }
+
+    emit!(RetireRewardEvent {
+        farm_state: farm_state_key,
+        reward_index,
+        reward_mint,
+        rewards_returned: reward_amount,
+        vault_swept_amount,
+        transfer_fee,
+        retire_grace_end_ts: farm_state.reward_infos[reward_index as usize].retire_grace_end_ts,
+        slot_freed,
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reward_index: u64)]
+pub struct RetireReward<'info> {
+    #[account(mut)]
+    pub farm_admin: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_admin,
+        has_one = farm_vaults_authority,
+        constraint = reward_index < farm_state.load()?.num_reward_tokens @ FarmError::RewardIndexOutOfRange,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(mut,
+        mint::token_program = token_program,
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    #[account(mut,
+        token::mint = reward_mint,
+        token::authority = farm_vaults_authority,
+        token::token_program = token_program,
+        constraint = reward_vault.key() == farm_state.load()?.reward_infos[reward_index as usize].rewards_vault @ FarmError::RewardVaultMismatch,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// CHECK: authority
+    #[account(
+        seeds = [BASE_SEED_FARM_VAULTS_AUTHORITY, farm_state.key().as_ref()],
+        bump,
+    )]
+    pub farm_vaults_authority: AccountInfo<'info>,
+
+    #[account(mut,
+        token::mint = reward_mint,
+        token::token_program = token_program,
+    )]
+    pub admin_reward_token_ata: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    farm_operations, state::UserState, utils::constraints::check_remaining_accounts, FarmError,
-    FarmState,
+    events::RewardUserOnceEvent, farm_operations, state::UserState,
+    utils::constraints::check_remaining_accounts, FarmError, FarmState,
 };
 
 
@@ -35,6 +35,15 @@ pub fn process(
 
     farm_operations::reward_user_once(&mut farm_state, &mut user_state, reward_index, amount)?;
 
+    emit!(RewardUserOnceEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        user_state: ctx.accounts.user_state.key(),
+        reward_index,
+        amount,
+        rewards_issued_unclaimed_before: expected_reward_issued_unclaimed,
+        rewards_issued_unclaimed_after: user_state.rewards_issued_unclaimed[reward_index as usize],
+    });
+
     Ok(())
 }
 
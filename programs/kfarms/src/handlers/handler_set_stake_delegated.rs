@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    farm_operations, state::TimeUnit, utils::constraints::check_remaining_accounts, FarmError,
-    FarmState, UserState,
+    events::SetStakeDelegatedEvent, farm_operations, state::TimeUnit,
+    utils::constraints::check_remaining_accounts, FarmError, FarmState, UserState,
 };
 
 pub fn process(ctx: Context<SetStakeDelegated>, new_stake: u64) -> Result<()> {
@@ -30,19 +30,27 @@ pub fn process(ctx: Context<SetStakeDelegated>, new_stake: u64) -> Result<()> {
    
    
 
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    let stake_before = user_state.active_stake_scaled;
+
     msg!(
         "SetStakeDelegated: prev:{} -> new:{} ts:{}",
-        user_state.active_stake_scaled,
+        stake_before,
         new_stake,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
+        ts,
     );
 
-    farm_operations::set_stake(
-        farm_state,
-        user_state,
-        new_stake,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
-    )?;
+    farm_operations::set_stake(farm_state, user_state, new_stake, ts)?;
+
+    emit!(SetStakeDelegatedEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        user_state: ctx.accounts.user_state.key(),
+        delegate_authority: ctx.accounts.delegate_authority.key(),
+        stake_before: stake_before.try_into().unwrap(),
+        stake_after: new_stake,
+        total_staked_amount: farm_state.total_staked_amount,
+        ts,
+    });
 
     Ok(())
 }
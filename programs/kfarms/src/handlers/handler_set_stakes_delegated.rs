@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::SetStakeDelegatedEvent, farm_operations, state::TimeUnit, FarmError, FarmState,
+    UserState,
+};
+
+pub fn process<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetStakesDelegated<'info>>,
+    new_stakes: Vec<u64>,
+) -> Result<()> {
+    let farm_state_key = ctx.accounts.farm_state.key();
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let time_unit = farm_state.time_unit;
+
+    require!(farm_state.is_delegated(), FarmError::FarmNotDelegated);
+    require!(
+        farm_state.delegate_authority == ctx.accounts.delegate_authority.key()
+            || farm_state.second_delegated_authority == ctx.accounts.delegate_authority.key(),
+        FarmError::AuthorityFarmDelegateMissmatch
+    );
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        new_stakes.len(),
+        FarmError::UnexpectedAccount
+    );
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "SetStakesDelegated: num_users:{} ts:{}",
+        new_stakes.len(),
+        ts
+    );
+
+    farm_operations::refresh_global_rewards(farm_state, None, ts)?;
+
+    for (user_state_info, new_stake) in ctx.remaining_accounts.iter().zip(new_stakes) {
+        let user_state_loader = AccountLoader::<UserState>::try_from(user_state_info)?;
+        let user_state = &mut user_state_loader.load_mut()?;
+        require_keys_eq!(
+            user_state.farm_state,
+            farm_state_key,
+            FarmError::UnexpectedAccount
+        );
+
+        let stake_before = user_state.active_stake_scaled;
+        msg!(
+            "SetStakesDelegated: user_state:{:?} prev:{} -> new:{}",
+            user_state_info.key(),
+            stake_before,
+            new_stake,
+        );
+
+        farm_operations::set_stake(farm_state, user_state, new_stake, ts)?;
+
+        emit!(SetStakeDelegatedEvent {
+            farm_state: farm_state_key,
+            user_state: user_state_info.key(),
+            delegate_authority: ctx.accounts.delegate_authority.key(),
+            stake_before: stake_before.try_into().unwrap(),
+            stake_after: new_stake,
+            total_staked_amount: farm_state.total_staked_amount,
+            ts,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetStakesDelegated<'info> {
+    pub delegate_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub farm_state: AccountLoader<'info, FarmState>,
+}
@@ -1,18 +1,32 @@
-use anchor_lang::{prelude::*, ToAccountInfo};
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_lang::{prelude::*, solana_program::program::set_return_data, ToAccountInfo};
+use anchor_spl::{
+    token::{Mint, Token, TokenAccount},
+    token_interface::{
+        Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+    },
+};
 
 use crate::{
-    farm_operations,
+    events::StakeEvent,
+    farm_operations, gen_signer_seeds_two,
     state::TimeUnit,
-    token_operations::transfer_from_user,
-    types::StakeEffects,
-    utils::{constraints::check_remaining_accounts, consts::*, scope::load_scope_price},
+    token_operations::{self, transfer_from_user},
+    types::{StakeEffects, StakeReturnData},
+    utils::{
+        constraints::{
+            check_receipt_accounts, check_remaining_accounts,
+            token_2022::validate_base_token_extensions,
+        },
+        consts::*,
+        scope::load_scope_price,
+    },
     FarmError, FarmState, UserState,
 };
 
-pub fn process(ctx: Context<Stake>, amount: u64) -> Result<()> {
+pub fn process(ctx: Context<Stake>, amount: u64, lock_duration: u64) -> Result<()> {
     require!(amount != 0, FarmError::StakeZero);
     check_remaining_accounts(&ctx)?;
+    validate_base_token_extensions(&ctx.accounts.token_mint.to_account_info())?;
 
     let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
     let user_state = &mut ctx.accounts.user_state.load_mut()?;
@@ -29,18 +43,24 @@ pub fn process(ctx: Context<Stake>, amount: u64) -> Result<()> {
    
     require!(!farm_state.is_delegated(), FarmError::FarmDelegated);
 
-    let StakeEffects { amount_to_stake } = farm_operations::stake(
+    let active_stake_scaled_before = user_state.active_stake_scaled;
+    let pending_deposit_stake_scaled_before = user_state.pending_deposit_stake_scaled;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+
+    let StakeEffects { amount_to_stake } = farm_operations::stake_locked(
         farm_state,
         user_state,
         scope_price,
         amount,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
+        lock_duration,
+        ts,
     )?;
 
     msg!(
-        "Stake {:} ts {:?}",
+        "Stake {:} lock_duration {:?} ts {:?}",
         amount_to_stake,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?)
+        lock_duration,
+        ts
     );
 
     if amount_to_stake > 0 {
@@ -50,9 +70,84 @@ pub fn process(ctx: Context<Stake>, amount: u64) -> Result<()> {
             &ctx.accounts.farm_vault.to_account_info(),
             &ctx.accounts.owner,
             &ctx.accounts.token_program,
+            &ctx.accounts.token_mint.to_account_info(),
         )?;
     }
 
+    if farm_state.has_receipt_mint() {
+        let receipt_amount = farm_operations::mint_receipt(user_state, active_stake_scaled_before)?;
+        msg!("Minting {} receipt tokens", receipt_amount);
+
+        if receipt_amount > 0 {
+            let (
+                Some(receipt_mint),
+                Some(user_receipt_ata),
+                Some(farm_vaults_authority),
+                Some(receipt_token_program),
+            ) = (
+                &ctx.accounts.receipt_mint,
+                &ctx.accounts.user_receipt_ata,
+                &ctx.accounts.farm_vaults_authority,
+                &ctx.accounts.receipt_token_program,
+            )
+            else {
+                return err!(FarmError::ReceiptAccountsMissing);
+            };
+            check_receipt_accounts(
+                farm_state,
+                &ctx.accounts.owner.key(),
+                &receipt_mint.key(),
+                &user_receipt_ata.key(),
+            )?;
+            require_keys_eq!(
+                farm_vaults_authority.key(),
+                farm_state.farm_vaults_authority,
+                FarmError::UnexpectedAccount
+            );
+
+            let farm_state_key = ctx.accounts.farm_state.key();
+            let signer_seeds: &[&[&[u8]]] = gen_signer_seeds_two!(
+                BASE_SEED_FARM_VAULTS_AUTHORITY,
+                farm_state_key,
+                farm_state.farm_vaults_authority_bump as u8
+            );
+            token_operations::mint_receipt(
+                receipt_amount,
+                signer_seeds,
+                &receipt_mint.to_account_info(),
+                &user_receipt_ata.to_account_info(),
+                farm_vaults_authority,
+                &receipt_token_program.to_account_info(),
+            )?;
+        }
+    }
+
+    emit!(StakeEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        user_state: ctx.accounts.user_state.key(),
+        owner: ctx.accounts.owner.key(),
+        amount: amount_to_stake,
+        active_stake_scaled_before,
+        active_stake_scaled_after: user_state.active_stake_scaled,
+        pending_deposit_stake_scaled_before,
+        pending_deposit_stake_scaled_after: user_state.pending_deposit_stake_scaled,
+        pending_deposit_stake_ts: user_state.pending_deposit_stake_ts,
+        boosted_stake_scaled_after: user_state.boosted_stake_scaled,
+        lock_end_ts: user_state.lock_end_ts,
+        total_staked_amount: farm_state.total_staked_amount,
+        total_active_stake_scaled: farm_state.total_active_stake_scaled,
+        total_pending_amount: farm_state.total_pending_amount,
+        ts,
+    });
+
+    set_return_data(
+        &StakeReturnData {
+            effects: StakeEffects { amount_to_stake },
+            user_stake: (&**user_state).into(),
+        }
+        .try_to_vec()?,
+    );
+
     Ok(())
 }
 
@@ -76,23 +171,37 @@ pub struct Stake<'info> {
         bump,
         constraint = farm_vault.delegate.is_none() @ FarmError::FarmVaultHasDelegate,
         constraint = farm_vault.close_authority.is_none() @ FarmError::FarmVaultHasCloseAuthority,
+        token::token_program = token_program,
     )]
-    pub farm_vault: Box<Account<'info, TokenAccount>>,
+    pub farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
    
     #[account(mut,
-        has_one = owner,
+        token::authority = owner,
         constraint = user_ata.mint == farm_state.load_mut()?.token.mint @ FarmError::UserAtaFarmTokenMintMissmatch,
+        token::token_program = token_program,
     )]
-    pub user_ata: Box<Account<'info, TokenAccount>>,
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     #[account(
         constraint = token_mint.key() == farm_state.load_mut()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        mint::token_program = token_program,
     )]
-    pub token_mint: Box<Account<'info, Mint>>,
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
 
     /// CHECK: Farm checks this
     pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub receipt_mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(mut)]
+    pub user_receipt_ata: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Farm checks this
+    pub farm_vaults_authority: Option<AccountInfo<'info>>,
+
+    pub receipt_token_program: Option<Program<'info, Token>>,
 }
@@ -0,0 +1,187 @@
+use anchor_lang::{prelude::*, Discriminator, ToAccountInfo};
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
+
+use crate::{
+    events::{InitializeUserEvent, StakeForEvent},
+    farm_operations,
+    state::TimeUnit,
+    token_operations::transfer_from_user,
+    types::{AccountLoaderState, StakeEffects},
+    utils::{
+        accessors::account_discriminator,
+        constraints::{check_remaining_accounts, token_2022::validate_base_token_extensions},
+        consts::*,
+        scope::load_scope_price,
+    },
+    FarmError, FarmState, UserState,
+};
+
+pub fn process(ctx: Context<StakeFor>, amount: u64) -> Result<()> {
+    require!(amount != 0, FarmError::StakeZero);
+    check_remaining_accounts(&ctx)?;
+    validate_base_token_extensions(&ctx.accounts.token_mint.to_account_info())?;
+
+    let user_account_state =
+        if let Ok(UserState::DISCRIMINATOR) = account_discriminator(&ctx.accounts.user_state) {
+            AccountLoaderState::Initialized
+        } else {
+            AccountLoaderState::Zeroed
+        };
+    let mut user_state = match user_account_state {
+        AccountLoaderState::Zeroed => ctx.accounts.user_state.load_init()?,
+        AccountLoaderState::Initialized => ctx.accounts.user_state.load_mut()?,
+    };
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+    let time_unit = farm_state.time_unit;
+    let owner = ctx.accounts.owner.key();
+    let farm_state_key = ctx.accounts.farm_state.key();
+
+    let amount = if amount == u64::MAX {
+        ctx.accounts.funder_ata.amount
+    } else {
+        amount
+    };
+
+    require!(!farm_state.is_delegated(), FarmError::FarmDelegated);
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+
+    match user_account_state {
+        AccountLoaderState::Zeroed => {
+            user_state.bump = ctx.bumps.user_state.into();
+            user_state.delegatee = owner;
+
+            farm_operations::initialize_user(
+                farm_state,
+                &mut user_state,
+                &owner,
+                &farm_state_key,
+                ts,
+            )?;
+
+            emit!(InitializeUserEvent {
+                farm_state: farm_state_key,
+                user_state: ctx.accounts.user_state.key(),
+                owner,
+                delegatee: user_state.delegatee,
+                user_id: user_state.user_id,
+                ts,
+            });
+        }
+        AccountLoaderState::Initialized => {
+            require_keys_eq!(
+                user_state.farm_state,
+                farm_state_key,
+                FarmError::StakeForUserStateMismatch
+            );
+            require_keys_eq!(
+                user_state.owner,
+                owner,
+                FarmError::StakeForUserStateMismatch
+            );
+            require_keys_eq!(
+                user_state.delegatee,
+                owner,
+                FarmError::StakeForUserStateMismatch
+            );
+        }
+    }
+
+    let active_stake_scaled_before = user_state.active_stake_scaled;
+    let pending_deposit_stake_scaled_before = user_state.pending_deposit_stake_scaled;
+
+    let StakeEffects { amount_to_stake } =
+        farm_operations::stake_for(farm_state, &mut user_state, scope_price, amount, ts)?;
+
+    msg!(
+        "StakeFor {:} funder {:?} owner {:?} ts {:?}",
+        amount_to_stake,
+        ctx.accounts.funder.key(),
+        owner,
+        ts
+    );
+
+    if amount_to_stake > 0 {
+        transfer_from_user(
+            amount_to_stake,
+            &ctx.accounts.funder_ata.to_account_info(),
+            &ctx.accounts.farm_vault.to_account_info(),
+            &ctx.accounts.funder,
+            &ctx.accounts.token_program,
+            &ctx.accounts.token_mint.to_account_info(),
+        )?;
+    }
+
+    emit!(StakeForEvent {
+        farm_state: farm_state_key,
+        user_state: ctx.accounts.user_state.key(),
+        owner,
+        funder: ctx.accounts.funder.key(),
+        amount: amount_to_stake,
+        active_stake_scaled_before,
+        active_stake_scaled_after: user_state.active_stake_scaled,
+        pending_deposit_stake_scaled_before,
+        pending_deposit_stake_scaled_after: user_state.pending_deposit_stake_scaled,
+        pending_deposit_stake_ts: user_state.pending_deposit_stake_ts,
+        total_staked_amount: farm_state.total_staked_amount,
+        total_active_stake_scaled: farm_state.total_active_stake_scaled,
+        total_pending_amount: farm_state.total_pending_amount,
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct StakeFor<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// CHECK: Owner of the farm position being credited, does not need to sign
+    pub owner: AccountInfo<'info>,
+
+    #[account(init_if_needed,
+        seeds = [BASE_SEED_USER_STATE, farm_state.key().as_ref(), owner.key().as_ref()],
+        bump,
+        payer = funder,
+        space = SIZE_USER_STATE,
+    )]
+    pub user_state: AccountLoader<'info, UserState>,
+
+    #[account(mut,
+        has_one = farm_vault,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(mut,
+        seeds = [BASE_SEED_FARM_VAULT, farm_state.key().as_ref(), farm_state.load()?.token.mint.as_ref()],
+        bump,
+        constraint = farm_vault.delegate.is_none() @ FarmError::FarmVaultHasDelegate,
+        constraint = farm_vault.close_authority.is_none() @ FarmError::FarmVaultHasCloseAuthority,
+        token::token_program = token_program,
+    )]
+    pub farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    #[account(mut,
+        token::authority = funder,
+        constraint = funder_ata.mint == farm_state.load()?.token.mint @ FarmError::UserAtaFarmTokenMintMissmatch,
+        token::token_program = token_program,
+    )]
+    pub funder_ata: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    #[account(
+        constraint = token_mint.key() == farm_state.load()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::StartFarmDecommissionEvent,
+    farm_operations,
+    state::TimeUnit,
+    utils::{constraints::check_remaining_accounts, scope::load_scope_price},
+    FarmState,
+};
+
+pub fn process(ctx: Context<StartFarmDecommission>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let time_unit = farm_state.time_unit;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "StartFarmDecommission farm_state {:?} ts {}",
+        ctx.accounts.farm_state.key(),
+        ts
+    );
+
+    farm_operations::start_decommission(farm_state, scope_price, ts)?;
+
+    emit!(StartFarmDecommissionEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        farm_admin: ctx.accounts.farm_admin.key(),
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct StartFarmDecommission<'info> {
+    pub farm_admin: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_admin,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::{
+    events::SyncReceiptEvent,
+    farm_operations,
+    state::TimeUnit,
+    utils::{constraints::check_remaining_accounts, scope::load_scope_price},
+    FarmError, FarmState, UserState,
+};
+
+pub fn process(ctx: Context<SyncReceipt>) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let sender_user_state = &mut ctx.accounts.sender_user_state.load_mut()?;
+    let receiver_user_state = &mut ctx.accounts.receiver_user_state.load_mut()?;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+    let ts = TimeUnit::now_from_clock(farm_state.time_unit, &Clock::get()?);
+
+    msg!(
+        "SyncReceipt sender_user_state {:?} receiver_user_state {:?} ts {}",
+        ctx.accounts.sender_user_state.key(),
+        ctx.accounts.receiver_user_state.key(),
+        ts
+    );
+
+    let amount = farm_operations::sync_receipt(
+        farm_state,
+        sender_user_state,
+        receiver_user_state,
+        scope_price,
+        ctx.accounts.sender_receipt_ata.amount,
+        ctx.accounts.receiver_receipt_ata.amount,
+        ts,
+    )?;
+
+    emit!(SyncReceiptEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        sender_user_state: ctx.accounts.sender_user_state.key(),
+        receiver_user_state: ctx.accounts.receiver_user_state.key(),
+        amount,
+        sender_receipt_amount_after: sender_user_state.receipt_amount,
+        receiver_receipt_amount_after: receiver_user_state.receipt_amount,
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SyncReceipt<'info> {
+    #[account(mut,
+        has_one = receipt_mint,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    #[account(mut,
+        constraint = sender_user_state.load()?.farm_state == farm_state.key() @ FarmError::UnexpectedAccount,
+    )]
+    pub sender_user_state: AccountLoader<'info, UserState>,
+
+    #[account(mut,
+        constraint = receiver_user_state.load()?.farm_state == farm_state.key() @ FarmError::UnexpectedAccount,
+    )]
+    pub receiver_user_state: AccountLoader<'info, UserState>,
+
+    pub receipt_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = receipt_mint,
+        associated_token::authority = sender_user_state.load()?.owner,
+    )]
+    pub sender_receipt_ata: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        associated_token::mint = receipt_mint,
+        associated_token::authority = receiver_user_state.load()?.owner,
+    )]
+    pub receiver_receipt_ata: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+}
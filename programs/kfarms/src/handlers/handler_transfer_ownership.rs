@@ -4,9 +4,10 @@ use anchor_lang::{
 };
 
 use crate::{
+    events::OwnershipTransferredEvent,
     farm_operations,
     state::UserState,
-    types::{AccountLoaderState, StakeEffects, WithdrawEffects},
+    types::{AccountLoaderState, TransferPositionEffects},
     utils::{
         accessors::account_discriminator,
         constraints::check_remaining_accounts,
@@ -16,11 +17,11 @@ use crate::{
     FarmError, FarmState, TimeUnit,
 };
 
-pub fn process(ctx: Context<TransferOwnership>) -> Result<()> {
+pub fn process(ctx: Context<TransferOwnership>, amount: u64) -> Result<()> {
+    require!(amount > 0, FarmError::UnstakeZero);
     check_remaining_accounts(&ctx)?;
 
     let old_user_state = &mut ctx.accounts.old_user_state.load_mut()?;
-    let old_user_state_stake = old_user_state.get_active_stake_decimal();
     let new_user_account_state =
         if let Ok(UserState::DISCRIMINATOR) = account_discriminator(&ctx.accounts.new_user_state) {
             AccountLoaderState::Initialized
@@ -46,27 +47,6 @@ pub fn process(ctx: Context<TransferOwnership>) -> Result<()> {
             old_user_state.owner,
             FarmError::InvalidTransferOwnershipUserStateOwnerDelegatee
         );
-       
-        require_eq!(
-            farm_state.locking_mode,
-            0,
-            FarmError::InvalidTransferOwnershipFarmStateLockingMode
-        );
-          
-          
-        require_eq!(
-            farm_state.withdrawal_cooldown_period,
-            0,
-            FarmError::InvalidTransferOwnershipFarmStateWithdrawCooldownPeriod
-        );
-
-       
-       
-        require_eq!(
-            farm_state.deposit_warmup_period,
-            0,
-            FarmError::InvalidTransferOwnershipFarmStateDepositWarmupPeriod
-        );
     }
 
     if matches!(new_user_account_state, AccountLoaderState::Initialized) {
@@ -109,43 +89,34 @@ pub fn process(ctx: Context<TransferOwnership>) -> Result<()> {
         )?;
     }
 
-   
-    farm_operations::unstake(
+    let TransferPositionEffects {
+        amount_transferred, ..
+    } = farm_operations::transfer_position(
         farm_state,
         old_user_state,
-        scope_price,
-        old_user_state_stake,
-        timestamp,
-    )?;
-
-   
-    let WithdrawEffects { amount_to_withdraw } =
-        farm_operations::withdraw_unstaked_deposits(farm_state, old_user_state, timestamp)?;
-
-   
-   
-    let StakeEffects { amount_to_stake } = farm_operations::stake_with_cap_check(
-        farm_state,
         &mut new_user_state,
         scope_price,
-        amount_to_withdraw,
+        amount,
         timestamp,
-        false,
     )?;
 
-    require_eq!(
-        amount_to_stake,
-        amount_to_withdraw,
-        FarmError::InvalidTransferOwnershipStakeAmount
-    );
-
     msg!(
         "Transferring stake of {} tokens from user_state {} to user_state {}",
-        amount_to_stake,
+        amount_transferred,
         ctx.accounts.old_user_state.key(),
         ctx.accounts.new_user_state.key()
     );
 
+    emit!(OwnershipTransferredEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        old_user_state: ctx.accounts.old_user_state.key(),
+        new_user_state: ctx.accounts.new_user_state.key(),
+        old_owner: ctx.accounts.old_owner.key(),
+        new_owner,
+        amount: amount_transferred,
+        ts: timestamp,
+    });
+
     Ok(())
 }
 
@@ -1,10 +1,17 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use decimal_wad::decimal::Decimal;
 
 use crate::{
+    events::{SlashedEvent, UnstakeEvent},
     farm_operations,
     state::TimeUnit,
-    utils::{constraints::check_remaining_accounts, scope::load_scope_price},
+    token_operations,
+    types::{UnstakeEffects, UnstakeReturnData},
+    utils::{
+        constraints::{check_receipt_accounts, check_remaining_accounts},
+        scope::load_scope_price,
+    },
     FarmError, FarmState, UserState,
 };
 
@@ -20,13 +27,80 @@ pub fn process(ctx: Context<Unstake>, amount: Decimal) -> Result<()> {
    
     require!(!farm_state.is_delegated(), FarmError::FarmDelegated);
 
-    farm_operations::unstake(
-        farm_state,
-        user_state,
-        scope_price,
-        amount,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
-    )?;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+
+    let UnstakeEffects {
+        stake_shares_unstaked_scaled,
+        amount_unstaked,
+        penalty_amount,
+    } = farm_operations::unstake(farm_state, user_state, scope_price, amount, ts)?;
+
+    if farm_state.has_receipt_mint() {
+        let receipt_amount = farm_operations::burn_receipt(user_state)?;
+        msg!("Burning {} receipt tokens", receipt_amount);
+
+        if receipt_amount > 0 {
+            let (Some(receipt_mint), Some(user_receipt_ata), Some(receipt_token_program)) = (
+                &ctx.accounts.receipt_mint,
+                &ctx.accounts.user_receipt_ata,
+                &ctx.accounts.receipt_token_program,
+            ) else {
+                return err!(FarmError::ReceiptAccountsMissing);
+            };
+            check_receipt_accounts(
+                farm_state,
+                &ctx.accounts.owner.key(),
+                &receipt_mint.key(),
+                &user_receipt_ata.key(),
+            )?;
+
+            token_operations::burn_receipt(
+                receipt_amount,
+                &receipt_mint.to_account_info(),
+                &user_receipt_ata.to_account_info(),
+                &ctx.accounts.owner.to_account_info(),
+                &receipt_token_program.to_account_info(),
+            )?;
+        }
+    }
+
+    emit!(UnstakeEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        user_state: ctx.accounts.user_state.key(),
+        owner: ctx.accounts.owner.key(),
+        stake_shares_scaled: stake_shares_unstaked_scaled,
+        amount_unstaked,
+        penalty_amount,
+        active_stake_scaled_after: user_state.active_stake_scaled,
+        pending_withdrawal_unstake_scaled_after: user_state.pending_withdrawal_unstake_scaled,
+        pending_withdrawal_unstake_ts: user_state.pending_withdrawal_unstake_ts,
+        total_staked_amount: farm_state.total_staked_amount,
+        total_pending_amount: farm_state.total_pending_amount,
+        ts,
+    });
+
+    if penalty_amount > 0 {
+        emit!(SlashedEvent {
+            farm_state: ctx.accounts.farm_state.key(),
+            user_state: ctx.accounts.user_state.key(),
+            penalty_amount,
+            slashed_amount_current: farm_state.slashed_amount_current,
+            slashed_amount_cumulative: farm_state.slashed_amount_cumulative,
+            ts,
+        });
+    }
+
+    set_return_data(
+        &UnstakeReturnData {
+            effects: UnstakeEffects {
+                stake_shares_unstaked_scaled,
+                amount_unstaked,
+                penalty_amount,
+            },
+            user_stake: (&**user_state).into(),
+        }
+        .try_to_vec()?,
+    );
 
     Ok(())
 }
@@ -47,4 +121,12 @@ pub struct Unstake<'info> {
 
     /// CHECK: Farm checks this
     pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+
+    #[account(mut)]
+    pub receipt_mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(mut)]
+    pub user_receipt_ata: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub receipt_token_program: Option<Program<'info, Token>>,
 }
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::{utils::constraints::check_remaining_accounts, FarmState};
+use crate::{
+    events::FarmAdminUpdatedEvent, utils::constraints::check_remaining_accounts, FarmState,
+};
 
 pub fn process(ctx: Context<UpdateFarmAdmin>) -> Result<()> {
     check_remaining_accounts(&ctx)?;
@@ -13,8 +15,15 @@ pub fn process(ctx: Context<UpdateFarmAdmin>) -> Result<()> {
         farm_state.pending_farm_admin
     );
 
+    let prev_farm_admin = farm_state.farm_admin;
     farm_state.farm_admin = farm_state.pending_farm_admin;
 
+    emit!(FarmAdminUpdatedEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        prev_farm_admin,
+        new_farm_admin: farm_state.farm_admin,
+    });
+
     Ok(())
 }
 
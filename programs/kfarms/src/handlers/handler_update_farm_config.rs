@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    events::FarmConfigUpdatedEvent,
     farm_operations,
-    state::FarmConfigOption,
+    state::{FarmConfigOption, TimeUnit},
     utils::{constraints::check_remaining_accounts, scope::load_scope_price},
     FarmError, FarmState,
 };
@@ -18,7 +19,9 @@ pub fn process(ctx: Context<UpdateFarmConfig>, mode: u16, data: &[u8]) -> Result
    
     if matches!(
         mode,
-        FarmConfigOption::UpdateRewardRps | FarmConfigOption::UpdateRewardScheduleCurvePoints
+        FarmConfigOption::UpdateRewardRps
+            | FarmConfigOption::UpdateRewardScheduleCurvePoints
+            | FarmConfigOption::UpdateRewardCurveMode
     ) {
         require!(
             farm_state.delegated_rps_admin == *ctx.accounts.signer.key
@@ -29,8 +32,21 @@ pub fn process(ctx: Context<UpdateFarmConfig>, mode: u16, data: &[u8]) -> Result
         require_keys_eq!(farm_state.farm_admin, *ctx.accounts.signer.key);
     }
 
+    require!(
+        farm_state.config_timelock_seconds == 0 || !mode.is_timelocked(),
+        FarmError::ConfigChangeTimelocked
+    );
+
     farm_operations::update_farm_config(farm_state, scope_price, mode, data)?;
 
+    emit!(FarmConfigUpdatedEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        signer: ctx.accounts.signer.key(),
+        mode: mode.into(),
+        data: data.to_vec(),
+        ts: TimeUnit::now_from_clock(farm_state.time_unit, &Clock::get()?),
+    });
+
     Ok(())
 }
 
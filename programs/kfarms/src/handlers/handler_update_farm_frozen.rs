@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::UpdateFarmFrozenEvent,
+    farm_operations,
+    state::TimeUnit,
+    utils::{constraints::check_remaining_accounts, scope::load_scope_price},
+    FarmState,
+};
+
+pub fn process(ctx: Context<UpdateFarmFrozen>, frozen: bool) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let time_unit = farm_state.time_unit;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "UpdateFarmFrozen farm_state {:?} frozen {} ts {}",
+        ctx.accounts.farm_state.key(),
+        frozen,
+        ts
+    );
+
+    farm_operations::update_farm_frozen(farm_state, scope_price, frozen, ts)?;
+
+    emit!(UpdateFarmFrozenEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        farm_admin: ctx.accounts.farm_admin.key(),
+        is_farm_frozen: farm_state.is_frozen(),
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateFarmFrozen<'info> {
+    pub farm_admin: Signer<'info>,
+
+    #[account(mut,
+        has_one = farm_admin,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    farm_operations, utils::constraints::check_remaining_accounts, GlobalConfig, GlobalConfigOption,
+    events::GlobalConfigUpdatedEvent, farm_operations,
+    utils::constraints::check_remaining_accounts, FarmError, GlobalConfig, GlobalConfigOption,
 };
 
 const VALUE_BYTE_ARRAY_LEN: usize = 32;
@@ -14,9 +15,21 @@ pub fn process(
     msg!("Update global config key={:?} value={:?}", key, value);
     check_remaining_accounts(&ctx)?;
     let global_config = &mut ctx.accounts.global_config.load_mut()?;
+    require!(
+        global_config.config_timelock_seconds == 0 || !key.is_timelocked(),
+        FarmError::ConfigChangeTimelocked
+    );
 
     farm_operations::update_global_config(global_config, key, value)?;
 
+    emit!(GlobalConfigUpdatedEvent {
+        global_config: ctx.accounts.global_config.key(),
+        mode: key as u8,
+        value: *value,
+        pending_global_admin: global_config.pending_global_admin,
+        treasury_fee_bps: global_config.treasury_fee_bps,
+    });
+
     Ok(())
 }
 
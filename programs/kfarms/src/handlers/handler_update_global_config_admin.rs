@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{utils::constraints::check_remaining_accounts, GlobalConfig};
+use crate::{
+    events::GlobalConfigAdminUpdatedEvent, utils::constraints::check_remaining_accounts,
+    GlobalConfig,
+};
 
 pub fn process(ctx: Context<UpdateGlobalConfigAdmin>) -> Result<()> {
     check_remaining_accounts(&ctx)?;
@@ -12,8 +15,15 @@ pub fn process(ctx: Context<UpdateGlobalConfigAdmin>) -> Result<()> {
         global_config.pending_global_admin
     );
 
+    let prev_global_admin = global_config.global_admin;
     global_config.global_admin = global_config.pending_global_admin;
 
+    emit!(GlobalConfigAdminUpdatedEvent {
+        global_config: ctx.accounts.global_config.key(),
+        prev_global_admin,
+        new_global_admin: global_config.global_admin,
+    });
+
     Ok(())
 }
 
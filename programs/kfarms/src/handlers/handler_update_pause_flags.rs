@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::UpdatePauseFlagsEvent,
+    farm_operations,
+    state::TimeUnit,
+    utils::{constraints::check_remaining_accounts, scope::load_scope_price},
+    FarmError, FarmState, GlobalConfig,
+};
+
+pub fn process(ctx: Context<UpdatePauseFlags>, flags: u64, pause: bool) -> Result<()> {
+    check_remaining_accounts(&ctx)?;
+
+    let farm_state = &mut ctx.accounts.farm_state.load_mut()?;
+    let global_config = &ctx.accounts.global_config.load()?;
+    let time_unit = farm_state.time_unit;
+    let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+    let signer = ctx.accounts.signer.key();
+
+    if pause {
+        require!(
+            signer == farm_state.farm_admin
+                || (farm_state.guardian != Pubkey::default() && signer == farm_state.guardian)
+                || (global_config.guardian != Pubkey::default()
+                    && signer == global_config.guardian),
+            FarmError::InvalidPauseAuthority
+        );
+    } else {
+        require_keys_eq!(
+            signer,
+            farm_state.farm_admin,
+            FarmError::InvalidPauseAuthority
+        );
+    }
+
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    msg!(
+        "UpdatePauseFlags farm_state {:?} flags {:#b} pause {} ts {}",
+        ctx.accounts.farm_state.key(),
+        flags,
+        pause,
+        ts
+    );
+
+    let pause_flags_before = farm_state.pause_flags;
+    let pause_flags = if pause {
+        pause_flags_before | flags
+    } else {
+        pause_flags_before & !flags
+    };
+
+    farm_operations::update_pause_flags(farm_state, scope_price, pause_flags, ts)?;
+
+    emit!(UpdatePauseFlagsEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        signer,
+        pause_flags_before,
+        pause_flags_after: farm_state.pause_flags,
+        ts,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePauseFlags<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut,
+        has_one = global_config,
+    )]
+    pub farm_state: AccountLoader<'info, FarmState>,
+
+    pub global_config: AccountLoader<'info, GlobalConfig>,
+
+    /// CHECK: Farm checks this
+    pub scope_prices: Option<AccountLoader<'info, scope::OraclePrices>>,
+}
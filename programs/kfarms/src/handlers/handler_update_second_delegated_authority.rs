@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{utils::constraints::check_remaining_accounts, FarmState, GlobalConfig};
+use crate::{
+    events::SecondDelegatedAuthorityUpdatedEvent, utils::constraints::check_remaining_accounts,
+    FarmState, GlobalConfig,
+};
 
 pub fn process(ctx: Context<UpdateSecondDelegatedAuthority>) -> Result<()> {
     check_remaining_accounts(&ctx)?;
@@ -14,8 +17,15 @@ pub fn process(ctx: Context<UpdateSecondDelegatedAuthority>) -> Result<()> {
         "new second_delegated_authority: {}",
         ctx.accounts.new_second_delegated_authority.key()
     );
+    let prev_second_delegated_authority = farm_state.second_delegated_authority;
     farm_state.second_delegated_authority = ctx.accounts.new_second_delegated_authority.key();
 
+    emit!(SecondDelegatedAuthorityUpdatedEvent {
+        farm_state: ctx.accounts.farm_state.key(),
+        prev_second_delegated_authority,
+        new_second_delegated_authority: farm_state.second_delegated_authority,
+    });
+
     Ok(())
 }
 
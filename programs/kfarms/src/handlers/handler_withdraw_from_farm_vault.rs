@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
 
 use crate::{
+    events::WithdrawFromFarmVaultEvent,
     farm_operations, gen_signer_seeds_two, token_operations,
     utils::{constraints::check_remaining_accounts, consts::*},
     FarmError, FarmState,
@@ -31,15 +34,26 @@ pub fn process(ctx: Context<WithdrawFromFarmVault>, amount_to_withdraw: u64) ->
         farm_state.farm_vaults_authority_bump as u8
     );
 
-    token_operations::transfer_from_vault(
+    token_operations::transfer_2022_from_vault(
         final_amount_to_withdraw,
         signer_seeds,
         &ctx.accounts.withdrawer_token_account.to_account_info(),
         &ctx.accounts.farm_vault.to_account_info(),
         &ctx.accounts.farm_vaults_authority,
         &ctx.accounts.token_program,
+        &ctx.accounts.token_mint.to_account_info(),
     )?;
 
+    emit!(WithdrawFromFarmVaultEvent {
+        farm_state: farm_state_key,
+        withdraw_authority: ctx.accounts.withdraw_authority.key(),
+        requested_amount: amount_to_withdraw,
+        amount: final_amount_to_withdraw,
+        total_staked_amount: farm_state.total_staked_amount,
+        total_pending_amount: farm_state.total_pending_amount,
+        is_farm_frozen: farm_state.is_farm_frozen != 0,
+    });
+
     Ok(())
 }
 
@@ -58,16 +72,18 @@ pub struct WithdrawFromFarmVault<'info> {
     #[account(mut,
         token::mint = farm_state.load()?.token.mint,
         token::authority = withdraw_authority,
+        token::token_program = token_program,
     )]
-    pub withdrawer_token_account: Box<Account<'info, TokenAccount>>,
+    pub withdrawer_token_account: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     #[account(mut,
         seeds = [BASE_SEED_FARM_VAULT, farm_state.key().as_ref(), farm_state.load()?.token.mint.as_ref()],
         bump,
         constraint = farm_vault.delegate.is_none() @ FarmError::FarmVaultHasDelegate,
         constraint = farm_vault.close_authority.is_none() @ FarmError::FarmVaultHasCloseAuthority,
+        token::token_program = token_program,
     )]
-    pub farm_vault: Box<Account<'info, TokenAccount>>,
+    pub farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     /// CHECK: Verified with a has_one constraint in farm_state
     #[account(
@@ -76,5 +92,11 @@ pub struct WithdrawFromFarmVault<'info> {
     )]
     pub farm_vaults_authority: AccountInfo<'info>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        address = farm_state.load()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
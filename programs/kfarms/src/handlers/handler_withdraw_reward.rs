@@ -4,11 +4,13 @@ use anchor_spl::token_interface::{
 };
 
 use crate::{
+    events::WithdrawRewardEvent,
     farm_operations, gen_signer_seeds_two,
     state::TimeUnit,
     token_operations,
     types::WithdrawRewardEffects,
     utils::{
+        accessors::mint_transfer_fee,
         constraints::{check_remaining_accounts, token_2022::validate_reward_token_extensions},
         consts::BASE_SEED_FARM_VAULTS_AUTHORITY,
         scope::load_scope_price,
@@ -25,21 +27,23 @@ pub fn process(ctx: Context<WithdrawReward>, amount: u64, reward_index: u64) ->
     let time_unit = farm_state.time_unit;
     let reward_mint = ctx.accounts.reward_vault.mint;
     let scope_price = load_scope_price(&ctx.accounts.scope_prices, farm_state)?;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
     msg!(
         "WithdrawReward farm_state {:?} amount {}, reward_index {} ts {}",
         ctx.accounts.farm_state.key(),
         amount,
         reward_index,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?)
+        ts
     );
 
+    let rewards_available_before = farm_state.reward_infos[reward_index as usize].rewards_available;
     let WithdrawRewardEffects { reward_amount } = farm_operations::withdraw_reward(
         farm_state,
         scope_price,
         &reward_mint,
         reward_index as usize,
         amount,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
+        ts,
     )?;
 
     msg!(
@@ -55,6 +59,13 @@ pub fn process(ctx: Context<WithdrawReward>, amount: u64, reward_index: u64) ->
         farm_state.farm_vaults_authority_bump as u8
     );
 
+    let transfer_fee = mint_transfer_fee(
+        &ctx.accounts.reward_mint.to_account_info(),
+        reward_amount,
+        Clock::get()?.epoch,
+    )?;
+    msg!("transfer fee {}", transfer_fee);
+
     token_operations::transfer_2022_from_vault(
         reward_amount,
         signer_seeds,
@@ -65,6 +76,17 @@ pub fn process(ctx: Context<WithdrawReward>, amount: u64, reward_index: u64) ->
         &ctx.accounts.reward_mint.to_account_info(),
     )?;
 
+    emit!(WithdrawRewardEvent {
+        farm_state: farm_state_key,
+        reward_index,
+        reward_mint,
+        amount: reward_amount,
+        transfer_fee,
+        rewards_available_before,
+        rewards_available_after: farm_state.reward_infos[reward_index as usize].rewards_available,
+        ts,
+    });
+
     Ok(())
 }
 
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
 
 use crate::{
+    events::WithdrawSlashedAmountEvent,
     farm_operations, gen_signer_seeds_two, token_operations,
     utils::{constraints::check_remaining_accounts, consts::*},
     FarmError, FarmState,
@@ -25,16 +28,24 @@ pub fn process(ctx: Context<WithdrawSlashedAmount>) -> Result<()> {
     );
 
     if amount_to_withdraw > 0 {
-        token_operations::transfer_from_vault(
+        token_operations::transfer_2022_from_vault(
             amount_to_withdraw,
             signer_seeds,
             &ctx.accounts.slashed_amount_spill_address.to_account_info(),
             &ctx.accounts.farm_vault.to_account_info(),
             &ctx.accounts.farm_vaults_authority,
             &ctx.accounts.token_program,
+            &ctx.accounts.token_mint.to_account_info(),
         )?;
     }
 
+    emit!(WithdrawSlashedAmountEvent {
+        farm_state: farm_state_key,
+        slashed_amount_spill_address: farm_state.slashed_amount_spill_address,
+        amount: amount_to_withdraw,
+        slashed_amount_cumulative: farm_state.slashed_amount_cumulative,
+    });
+
     Ok(())
 }
 
@@ -52,16 +63,18 @@ pub struct WithdrawSlashedAmount<'info> {
 
     #[account(mut,
         token::mint = farm_state.load()?.token.mint,
+        token::token_program = token_program,
     )]
-    pub slashed_amount_spill_address: Box<Account<'info, TokenAccount>>,
+    pub slashed_amount_spill_address: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     #[account(mut,
         seeds = [BASE_SEED_FARM_VAULT, farm_state.key().as_ref(), farm_state.load()?.token.mint.as_ref()],
         bump,
         constraint = farm_vault.delegate.is_none() @ FarmError::FarmVaultHasDelegate,
         constraint = farm_vault.close_authority.is_none() @ FarmError::FarmVaultHasCloseAuthority,
+        token::token_program = token_program,
     )]
-    pub farm_vault: Box<Account<'info, TokenAccount>>,
+    pub farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     /// CHECK: Verified with a has_one constraint in farm_state
     #[account(
@@ -70,5 +83,11 @@ pub struct WithdrawSlashedAmount<'info> {
     )]
     pub farm_vaults_authority: AccountInfo<'info>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        address = farm_state.load()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
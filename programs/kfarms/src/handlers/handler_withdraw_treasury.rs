@@ -4,9 +4,11 @@ use anchor_spl::token_interface::{
 };
 
 use crate::{
+    events::WithdrawTreasuryEvent,
     state::GlobalConfig,
     token_operations,
     utils::{
+        accessors::mint_transfer_fee,
         constraints::{check_remaining_accounts, token_2022::validate_reward_token_extensions},
         consts::*,
     },
@@ -25,11 +27,18 @@ pub fn process(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
         &[ctx.bumps.treasury_vault_authority],
     ]];
 
+    let transfer_fee = mint_transfer_fee(
+        &ctx.accounts.reward_mint.to_account_info(),
+        amount,
+        Clock::get()?.epoch,
+    )?;
+
     if amount > 0 {
         xmsg!(
-            "WithdrawTreasury amount: {}, available amount: {}",
+            "WithdrawTreasury amount: {}, available amount: {}, transfer fee: {}",
             amount,
-            ctx.accounts.reward_treasury_vault.amount
+            ctx.accounts.reward_treasury_vault.amount,
+            transfer_fee
         );
         token_operations::transfer_2022_from_vault(
             amount,
@@ -46,6 +55,14 @@ pub fn process(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
         return Err(FarmError::NothingToWithdraw.into());
     }
 
+    emit!(WithdrawTreasuryEvent {
+        global_config: global_config_key,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        destination: ctx.accounts.withdraw_destination_token_account.key(),
+        amount,
+        transfer_fee,
+    });
+
     Ok(())
 }
 
@@ -1,11 +1,14 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use anchor_spl::token_interface::{
+    Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+};
 
 use crate::{
+    events::WithdrawUnstakedDepositsEvent,
     farm_operations, gen_signer_seeds_two,
     state::TimeUnit,
     token_operations,
-    types::WithdrawEffects,
+    types::{WithdrawEffects, WithdrawReturnData},
     utils::{constraints::check_remaining_accounts, consts::*},
     FarmError, FarmState, UserState,
 };
@@ -20,11 +23,9 @@ pub fn process(ctx: Context<WithdrawUnstakedDeposits>) -> Result<()> {
    
     require!(!farm_state.is_delegated(), FarmError::FarmDelegated);
 
-    let WithdrawEffects { amount_to_withdraw } = farm_operations::withdraw_unstaked_deposits(
-        farm_state,
-        user_state,
-        TimeUnit::now_from_clock(time_unit, &Clock::get()?),
-    )?;
+    let ts = TimeUnit::now_from_clock(time_unit, &Clock::get()?);
+    let WithdrawEffects { amount_to_withdraw } =
+        farm_operations::withdraw_unstaked_deposits(farm_state, user_state, ts)?;
 
     let farm_state_key = ctx.accounts.farm_state.key();
     let signer_seeds: &[&[&[u8]]] = gen_signer_seeds_two!(
@@ -34,16 +35,34 @@ pub fn process(ctx: Context<WithdrawUnstakedDeposits>) -> Result<()> {
     );
 
     if amount_to_withdraw > 0 {
-        token_operations::transfer_from_vault(
+        token_operations::transfer_2022_from_vault(
             amount_to_withdraw,
             signer_seeds,
             &ctx.accounts.user_ata.to_account_info(),
             &ctx.accounts.farm_vault.to_account_info(),
             &ctx.accounts.farm_vaults_authority,
             &ctx.accounts.token_program,
+            &ctx.accounts.token_mint.to_account_info(),
         )?;
     }
 
+    emit!(WithdrawUnstakedDepositsEvent {
+        farm_state: farm_state_key,
+        user_state: ctx.accounts.user_state.key(),
+        owner: ctx.accounts.owner.key(),
+        amount: amount_to_withdraw,
+        total_pending_amount: farm_state.total_pending_amount,
+        ts,
+    });
+
+    set_return_data(
+        &WithdrawReturnData {
+            effects: WithdrawEffects { amount_to_withdraw },
+            user_stake: (&**user_state).into(),
+        }
+        .try_to_vec()?,
+    );
+
     Ok(())
 }
 
@@ -66,18 +85,20 @@ pub struct WithdrawUnstakedDeposits<'info> {
 
    
     #[account(mut,
-        has_one = owner,
+        token::authority = owner,
         constraint = user_ata.mint == farm_state.load()?.token.mint @ FarmError::UserAtaFarmTokenMintMissmatch,
+        token::token_program = token_program,
     )]
-    pub user_ata: Box<Account<'info, TokenAccount>>,
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     #[account(mut,
         seeds = [BASE_SEED_FARM_VAULT, farm_state.key().as_ref(), farm_state.load()?.token.mint.as_ref()],
         bump,
         constraint = farm_vault.delegate.is_none() @ FarmError::FarmVaultHasDelegate,
         constraint = farm_vault.close_authority.is_none() @ FarmError::FarmVaultHasCloseAuthority,
+        token::token_program = token_program,
     )]
-    pub farm_vault: Box<Account<'info, TokenAccount>>,
+    pub farm_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
 
     /// CHECK: Verified with a has_one constraint in farm pool state
     #[account(
@@ -86,5 +107,11 @@ pub struct WithdrawUnstakedDeposits<'info> {
     )]
     pub farm_vaults_authority: AccountInfo<'info>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        address = farm_state.load()?.token.mint @ FarmError::TokenFarmTokenMintMissmatch,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
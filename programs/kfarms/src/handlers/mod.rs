@@ -1,22 +1,46 @@
 pub mod handler_add_reward;
+pub mod handler_cancel_config_change;
+pub mod handler_claim_reward_distribution;
+pub mod handler_claim_vested;
+pub mod handler_close_reward_distribution;
+pub mod handler_close_user_state;
+pub mod handler_compound_reward;
+pub mod handler_create_reward_distribution;
+pub mod handler_decommission_farm;
 pub mod handler_deposit_to_farm_vault;
+pub mod handler_execute_farm_config_change;
+pub mod handler_execute_global_config_change;
+pub mod handler_get_user_pending_rewards;
+pub mod handler_harvest_all_rewards;
 pub mod handler_harvest_reward;
 pub mod handler_initialize_farm;
 pub mod handler_initialize_farm_delegated;
+pub mod handler_initialize_farm_with_receipt_mint;
 pub mod handler_initialize_global_config;
 pub mod handler_initialize_reward;
 pub mod handler_initialize_user;
+pub mod handler_migrate_stake;
+pub mod handler_queue_farm_config_change;
+pub mod handler_queue_global_config_change;
 pub mod handler_refresh_farm;
+pub mod handler_refresh_user_boosts;
 pub mod handler_refresh_user_state;
+pub mod handler_retire_reward;
 pub mod handler_reward_user_once;
 pub mod handler_set_stake_delegated;
+pub mod handler_set_stakes_delegated;
 pub mod handler_stake;
+pub mod handler_stake_for;
+pub mod handler_start_farm_decommission;
+pub mod handler_sync_receipt;
 pub mod handler_transfer_ownership;
 pub mod handler_unstake;
 pub mod handler_update_farm_admin;
 pub mod handler_update_farm_config;
+pub mod handler_update_farm_frozen;
 pub mod handler_update_global_config;
 pub mod handler_update_global_config_admin;
+pub mod handler_update_pause_flags;
 pub mod handler_update_second_delegated_authority;
 pub mod handler_withdraw_from_farm_vault;
 pub mod handler_withdraw_reward;
@@ -25,24 +49,48 @@ pub mod handler_withdraw_treasury;
 pub mod handler_withdraw_unstaked_deposits;
 
 pub use handler_add_reward::*;
+pub use handler_cancel_config_change::*;
+pub use handler_claim_reward_distribution::*;
+pub use handler_claim_vested::*;
+pub use handler_close_reward_distribution::*;
+pub use handler_close_user_state::*;
+pub use handler_compound_reward::*;
+pub use handler_create_reward_distribution::*;
+pub use handler_decommission_farm::*;
 pub use handler_deposit_to_farm_vault::*;
+pub use handler_execute_farm_config_change::*;
+pub use handler_execute_global_config_change::*;
+pub use handler_get_user_pending_rewards::*;
+pub use handler_harvest_all_rewards::*;
 pub use handler_harvest_reward::*;
 pub use handler_initialize_farm::*;
 pub use handler_initialize_farm_delegated::*;
+pub use handler_initialize_farm_with_receipt_mint::*;
 pub use handler_initialize_global_config::*;
 pub use handler_initialize_reward::*;
 pub use handler_initialize_user::*;
+pub use handler_migrate_stake::*;
+pub use handler_queue_farm_config_change::*;
+pub use handler_queue_global_config_change::*;
 pub use handler_refresh_farm::*;
+pub use handler_refresh_user_boosts::*;
 pub use handler_refresh_user_state::*;
+pub use handler_retire_reward::*;
 pub use handler_reward_user_once::*;
 pub use handler_set_stake_delegated::*;
+pub use handler_set_stakes_delegated::*;
 pub use handler_stake::*;
+pub use handler_stake_for::*;
+pub use handler_start_farm_decommission::*;
+pub use handler_sync_receipt::*;
 pub use handler_transfer_ownership::*;
 pub use handler_unstake::*;
 pub use handler_update_farm_admin::*;
 pub use handler_update_farm_config::*;
+pub use handler_update_farm_frozen::*;
 pub use handler_update_global_config::*;
 pub use handler_update_global_config_admin::*;
+pub use handler_update_pause_flags::*;
 pub use handler_update_second_delegated_authority::*;
 pub use handler_withdraw_from_farm_vault::*;
 pub use handler_withdraw_reward::*;
@@ -1,5 +1,6 @@
 #![allow(clippy::result_large_err)]
 
+pub mod events;
 pub mod farm_operations;
 mod handlers;
 pub mod stake_operations;
@@ -60,6 +61,12 @@ pub mod farms {
         handler_initialize_farm_delegated::process(ctx)
     }
 
+    pub fn initialize_farm_with_receipt_mint(
+        ctx: Context<InitializeFarmWithReceiptMint>,
+    ) -> Result<()> {
+        handler_initialize_farm_with_receipt_mint::process(ctx)
+    }
+
     pub fn initialize_reward(ctx: Context<InitializeReward>) -> Result<()> {
         handler_initialize_reward::process(ctx)
     }
@@ -80,8 +87,8 @@ pub mod farms {
         handler_initialize_user::process(ctx)
     }
 
-    pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
-        handler_transfer_ownership::process(ctx)
+    pub fn transfer_ownership(ctx: Context<TransferOwnership>, amount: u64) -> Result<()> {
+        handler_transfer_ownership::process(ctx, amount)
     }
 
     pub fn reward_user_once(
@@ -103,13 +110,20 @@ pub mod farms {
     }
 
     pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
-        handler_stake::process(ctx, amount)
+        handler_stake::process(ctx, amount, 0)
     }
 
     pub fn set_stake_delegated(ctx: Context<SetStakeDelegated>, new_amount: u64) -> Result<()> {
         handler_set_stake_delegated::process(ctx, new_amount)
     }
 
+    pub fn set_stakes_delegated<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetStakesDelegated<'info>>,
+        new_amounts: Vec<u64>,
+    ) -> Result<()> {
+        handler_set_stakes_delegated::process(ctx, new_amounts)
+    }
+
     pub fn harvest_reward(ctx: Context<HarvestReward>, reward_index: u64) -> Result<()> {
        
         handler_harvest_reward::process(ctx, reward_index)
@@ -123,6 +137,12 @@ pub mod farms {
         handler_refresh_user_state::process(ctx)
     }
 
+    pub fn refresh_user_boosts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefreshUserBoosts<'info>>,
+    ) -> Result<()> {
+        handler_refresh_user_boosts::process(ctx)
+    }
+
     pub fn withdraw_unstaked_deposits(ctx: Context<WithdrawUnstakedDeposits>) -> Result<()> {
         handler_withdraw_unstaked_deposits::process(ctx)
     }
@@ -168,6 +188,139 @@ pub mod farms {
         handler_update_second_delegated_authority::process(ctx)
     }
 
+    pub fn close_user_state(ctx: Context<CloseUserState>) -> Result<()> {
+        handler_close_user_state::process(ctx)
+    }
+
+    pub fn harvest_all_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestAllRewards<'info>>,
+    ) -> Result<()> {
+        handler_harvest_all_rewards::process(ctx)
+    }
+
+    pub fn compound_reward(ctx: Context<CompoundReward>, reward_index: u64) -> Result<()> {
+        handler_compound_reward::process(ctx, reward_index)
+    }
+
+    pub fn stake_for(ctx: Context<StakeFor>, amount: u64) -> Result<()> {
+        handler_stake_for::process(ctx, amount)
+    }
+
+    pub fn stake_locked(ctx: Context<Stake>, amount: u64, lock_duration: u64) -> Result<()> {
+        handler_stake::process(ctx, amount, lock_duration)
+    }
+
+    pub fn start_farm_decommission(ctx: Context<StartFarmDecommission>) -> Result<()> {
+        handler_start_farm_decommission::process(ctx)
+    }
+
+    pub fn decommission_farm<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DecommissionFarm<'info>>,
+    ) -> Result<()> {
+        handler_decommission_farm::process(ctx)
+    }
+
+    pub fn retire_reward(
+        ctx: Context<RetireReward>,
+        reward_index: u64,
+        grace_period: u64,
+    ) -> Result<()> {
+        handler_retire_reward::process(ctx, reward_index, grace_period)
+    }
+
+    pub fn freeze_farm(ctx: Context<UpdateFarmFrozen>) -> Result<()> {
+        handler_update_farm_frozen::process(ctx, true)
+    }
+
+    pub fn unfreeze_farm(ctx: Context<UpdateFarmFrozen>) -> Result<()> {
+        handler_update_farm_frozen::process(ctx, false)
+    }
+
+    pub fn pause_farm_operations(ctx: Context<UpdatePauseFlags>, flags: u64) -> Result<()> {
+        handler_update_pause_flags::process(ctx, flags, true)
+    }
+
+    pub fn unpause_farm_operations(ctx: Context<UpdatePauseFlags>, flags: u64) -> Result<()> {
+        handler_update_pause_flags::process(ctx, flags, false)
+    }
+
+    pub fn queue_farm_config_change(
+        ctx: Context<QueueFarmConfigChange>,
+        mode: u16,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        handler_queue_farm_config_change::process(ctx, mode, &data)
+    }
+
+    pub fn queue_global_config_change(
+        ctx: Context<QueueGlobalConfigChange>,
+        mode: u8,
+        value: [u8; 32],
+    ) -> Result<()> {
+        let mode =
+            GlobalConfigOption::try_from(mode).map_err(|_| FarmError::InvalidGlobalConfigMode)?;
+        handler_queue_global_config_change::process(ctx, mode, &value)
+    }
+
+    pub fn execute_farm_config_change(ctx: Context<ExecuteFarmConfigChange>) -> Result<()> {
+        handler_execute_farm_config_change::process(ctx)
+    }
+
+    pub fn execute_global_config_change(ctx: Context<ExecuteGlobalConfigChange>) -> Result<()> {
+        handler_execute_global_config_change::process(ctx)
+    }
+
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        handler_cancel_config_change::process(ctx)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>, reward_index: u64) -> Result<()> {
+        handler_claim_vested::process(ctx, reward_index)
+    }
+
+    pub fn create_reward_distribution(
+        ctx: Context<CreateRewardDistribution>,
+        reward_index: u64,
+        distribution_id: u64,
+        merkle_root: [u8; 32],
+        total_amount: u64,
+        num_leaves: u64,
+    ) -> Result<()> {
+        handler_create_reward_distribution::process(
+            ctx,
+            reward_index,
+            distribution_id,
+            merkle_root,
+            total_amount,
+            num_leaves,
+        )
+    }
+
+    pub fn claim_reward_distribution(
+        ctx: Context<ClaimRewardDistribution>,
+        leaf_index: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handler_claim_reward_distribution::process(ctx, leaf_index, amount, proof)
+    }
+
+    pub fn close_reward_distribution(ctx: Context<CloseRewardDistribution>) -> Result<()> {
+        handler_close_reward_distribution::process(ctx)
+    }
+
+    pub fn migrate_stake(ctx: Context<MigrateStake>) -> Result<()> {
+        handler_migrate_stake::process(ctx)
+    }
+
+    pub fn sync_receipt(ctx: Context<SyncReceipt>) -> Result<()> {
+        handler_sync_receipt::process(ctx)
+    }
+
+    pub fn get_user_pending_rewards(ctx: Context<GetUserPendingRewards>) -> Result<()> {
+        handler_get_user_pending_rewards::process(ctx)
+    }
+
     pub fn idl_missing_types(
         _ctx: Context<UpdateGlobalConfig>,
         _global_config_option_kind: GlobalConfigOption,
@@ -175,6 +328,7 @@ pub mod farms {
         _time_unit: TimeUnit,
         _locking_mode: LockingMode,
         _reward_type: RewardType,
+        _reward_curve_mode: RewardCurveMode,
     ) -> Result<()> {
         unreachable!("This should never be called")
     }
@@ -387,6 +541,141 @@ pub enum FarmError {
    
     #[msg("Current reward issued unclaimed does not match expected value")]
     CurrentRewardIssuedUnclaimedMismatch,
+   
+    #[msg("User state still has active, pending deposit or pending withdrawal stake")]
+    UserStateHasStake,
+   
+    #[msg("User state still has unclaimed rewards")]
+    UserStateHasUnclaimedRewards,
+   
+    #[msg("Reward mint does not match the farm token mint, cannot compound")]
+    CompoundRewardMintNotFarmToken,
+   
+    #[msg("Target user state does not belong to the given owner and farm")]
+    StakeForUserStateMismatch,
+   
+    #[msg("Lock boosts are not enabled for this farm")]
+    BoostNotEnabled,
+   
+    #[msg("Lock duration exceeds the farm maximum lock duration")]
+    InvalidLockDuration,
+   
+    #[msg("User stake is locked until the lock end timestamp")]
+    UserStakeLocked,
+   
+    #[msg("Farm is being decommissioned")]
+    FarmDecommissioning,
+   
+    #[msg("Farm decommissioning has not been started")]
+    FarmNotDecommissioning,
+   
+    #[msg("Farm still has users, stake or pending amounts")]
+    FarmNotEmpty,
+   
+    #[msg("Reward is being retired")]
+    RewardRetiring,
+   
+    #[msg("Reward has unclaimed user rewards, a grace period is required")]
+    RewardHasUnclaimedRewards,
+   
+    #[msg("Reward retirement grace period has not elapsed")]
+    RewardRetireGracePeriodNotElapsed,
+   
+    #[msg("Farm is frozen")]
+    FarmFrozen,
+   
+    #[msg("Farm is already in the requested frozen state")]
+    FarmFrozenStateUnchanged,
+   
+    #[msg("Operation is paused for this farm")]
+    OperationPaused,
+   
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+   
+    #[msg("Signer is not allowed to update pause flags")]
+    InvalidPauseAuthority,
+   
+    #[msg("Config change is timelocked and must be queued")]
+    ConfigChangeTimelocked,
+   
+    #[msg("Config change timelock has not elapsed")]
+    ConfigChangeTimelockNotElapsed,
+   
+    #[msg("Pending config change does not match the target account")]
+    ConfigChangeTargetMismatch,
+   
+    #[msg("Pending config change was queued by a different admin")]
+    ConfigChangeAdminMismatch,
+   
+    #[msg("Signer is not allowed to cancel the config change")]
+    InvalidConfigChangeCancelAuthority,
+   
+    #[msg("No vested rewards to claim")]
+    NothingToClaimVested,
+   
+    #[msg("Distribution has too many leaves")]
+    DistributionTooManyLeaves,
+   
+    #[msg("Distribution amount is invalid")]
+    DistributionInvalidAmount,
+   
+    #[msg("Not enough available rewards to fund the distribution")]
+    DistributionInsufficientRewards,
+   
+    #[msg("Invalid merkle proof")]
+    DistributionInvalidProof,
+   
+    #[msg("Distribution leaf already claimed")]
+    DistributionLeafAlreadyClaimed,
+   
+    #[msg("Distribution leaf index out of range")]
+    DistributionLeafIndexOutOfRange,
+   
+    #[msg("Distribution reward no longer matches the farm reward")]
+    DistributionRewardMismatch,
+   
+    #[msg("Reward has active distributions")]
+    RewardHasActiveDistributions,
+   
+    #[msg("Farm does not allow migration to this target")]
+    MigrationTargetNotAllowed,
+   
+    #[msg("Source and destination farms use different mints")]
+    MigrationMintMismatch,
+   
+    #[msg("Farm already has a receipt mint")]
+    ReceiptMintAlreadyInitialized,
+   
+    #[msg("Receipt mint requires a farm without deposit warmup")]
+    ReceiptMintRequiresNoWarmup,
+   
+    #[msg("Receipt mint accounts are required for this farm")]
+    ReceiptAccountsMissing,
+   
+    #[msg("Stake backed by receipt tokens can only move with sync_receipt")]
+    ReceiptBackedStake,
+   
+    #[msg("No receipt balance difference to sync")]
+    NothingToSyncReceipt,
+   
+    #[msg("Signer does not own the user state")]
+    UserStateOwnerMismatch,
+   
+    #[msg("Farm still has open reward distributions")]
+    FarmHasOpenDistributions,
+   
+    #[msg("Reward slot has been reused too many times and cannot be freed again")]
+    RewardSlotGenerationExhausted,
+   
+    #[msg("Transfer would extend the lock of the receiver's existing position")]
+    TransferWouldExtendLock,
+   
+    #[msg("Receiver does not have enough free pending deposit or withdrawal slots")]
+    TransferPendingQueueFull,
+   
+    #[msg("Operation is not supported on farms with a receipt mint")]
+    ReceiptMintNotSupported,
 }
 
 impl From<DecimalError> for FarmError {
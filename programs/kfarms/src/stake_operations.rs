@@ -361,15 +361,18 @@ pub fn add_pending_deposit_stake(
 pub fn remove_pending_deposit_stake(
     user_stake: &mut impl UserStakeAccessor,
     farm: &mut impl FarmStakeAccessor,
+    stake_to_remove: Decimal,
 ) -> Result<u64, FarmError> {
     let mut user_stake = user_stake.get_accessor();
     let mut farm = farm.get_accessor();
 
+    let stake_to_remove = std::cmp::min(stake_to_remove, user_stake.pending_deposit_stake);
+
    
    
    
     let pending_amount_removed: u64 = convert_stake_to_amount(
-        user_stake.pending_deposit_stake,
+        stake_to_remove,
         farm.total_pending_stake,
         farm.total_pending_amount,
         false,
@@ -377,9 +380,9 @@ pub fn remove_pending_deposit_stake(
 
     farm.total_pending_amount -= pending_amount_removed;
 
-    farm.total_pending_stake = farm.total_pending_stake - user_stake.pending_deposit_stake;
+    farm.total_pending_stake = farm.total_pending_stake - stake_to_remove;
 
-    user_stake.pending_deposit_stake = Decimal::zero();
+    user_stake.pending_deposit_stake = user_stake.pending_deposit_stake - stake_to_remove;
 
     Ok(pending_amount_removed)
 }
@@ -413,8 +416,9 @@ pub fn add_active_stake(
 pub fn activate_pending_stake(
     user_stake: &mut impl UserStakeAccessor,
     farm: &mut impl FarmStakeAccessor,
+    stake_to_activate: Decimal,
 ) -> Result<(u64, Decimal), FarmError> {
-    let amount_to_stake = remove_pending_deposit_stake(user_stake, farm)?;
+    let amount_to_stake = remove_pending_deposit_stake(user_stake, farm, stake_to_activate)?;
     let gained_active_stake = add_active_stake(user_stake, farm, amount_to_stake)?;
     Ok((amount_to_stake, gained_active_stake))
 }
@@ -535,22 +539,25 @@ pub fn unstake(
 pub fn remove_pending_withdrawal_stake(
     user_stake: &mut impl UserStakeAccessor,
     farm: &mut impl FarmStakeAccessor,
+    stake_to_remove: Decimal,
 ) -> Result<u64, FarmError> {
     let mut user_stake = user_stake.get_accessor();
     let mut farm = farm.get_accessor();
 
+    let stake_to_remove = std::cmp::min(stake_to_remove, user_stake.pending_withdrawal_unstake);
+
    
     let pending_amount_removed: u64 = convert_stake_to_amount(
-        user_stake.pending_withdrawal_unstake,
+        stake_to_remove,
         farm.total_pending_stake,
         farm.total_pending_amount,
         false,
     );
 
     farm.total_pending_amount -= pending_amount_removed;
-    farm.total_pending_stake = farm.total_pending_stake - user_stake.pending_withdrawal_unstake;
+    farm.total_pending_stake = farm.total_pending_stake - stake_to_remove;
 
-    user_stake.pending_withdrawal_unstake = Decimal::zero();
+    user_stake.pending_withdrawal_unstake = user_stake.pending_withdrawal_unstake - stake_to_remove;
 
     Ok(pending_amount_removed)
 }
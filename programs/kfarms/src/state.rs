@@ -7,8 +7,11 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 use scope::DatedPrice;
 
 use crate::{
+    dbg_msg,
     utils::{
-        consts::{self, MAX_REWARDS_TOKENS, REWARD_CURVE_POINTS},
+        consts::{
+            self, MAX_DEPOSIT_LOTS, MAX_REWARDS_TOKENS, MAX_WITHDRAWAL_TICKETS, REWARD_CURVE_POINTS,
+        },
         math::ten_pow,
     },
     xmsg, FarmError,
@@ -31,7 +34,12 @@ pub struct GlobalConfig {
 
     pub pending_global_admin: Pubkey,
 
-    pub _padding1: [u128; 126],
+    pub guardian: Pubkey,
+
+    pub config_timelock_seconds: u64,
+    pub _padding0: u64,
+
+    pub _padding1: [u128; 123],
 }
 
 impl Default for GlobalConfig {
@@ -43,7 +51,10 @@ impl Default for GlobalConfig {
             treasury_vaults_authority_bump: 0,
             treasury_fee_bps: 0,
             pending_global_admin: Pubkey::default(),
-            _padding1: [0; 126],
+            guardian: Pubkey::default(),
+            config_timelock_seconds: 0,
+            _padding0: 0,
+            _padding1: [0; 123],
         }
     }
 }
@@ -55,6 +66,20 @@ impl Default for GlobalConfig {
 pub enum GlobalConfigOption {
     SetPendingGlobalAdmin = 0,
     SetTreasuryFeeBps = 1,
+    SetGuardian = 2,
+    SetConfigTimelock = 3,
+}
+
+impl GlobalConfigOption {
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            GlobalConfigOption::SetPendingGlobalAdmin
+                | GlobalConfigOption::SetTreasuryFeeBps
+                | GlobalConfigOption::SetGuardian
+                | GlobalConfigOption::SetConfigTimelock
+        )
+    }
 }
 
 static_assertions::const_assert_eq!(0, std::mem::size_of::<FarmState>() % 8);
@@ -104,7 +129,9 @@ pub struct FarmState {
 
     pub is_harvesting_permissionless: u8,
 
-    pub _padding0: [u8; 3],
+    pub is_decommissioning: u8,
+
+    pub _padding0: [u8; 2],
 
 
 
@@ -150,7 +177,22 @@ pub struct FarmState {
     pub vault_id: Pubkey,
     pub second_delegated_authority: Pubkey,
 
-    pub _padding: [u64; 74],
+    pub boost_max_multiplier_bps: u64,
+    pub boost_max_lock_duration: u64,
+    pub total_boosted_stake_scaled: u128,
+
+    pub guardian: Pubkey,
+    pub pause_flags: u64,
+
+    pub config_timelock_seconds: u64,
+
+    pub migration_target: Pubkey,
+
+    pub receipt_mint: Pubkey,
+
+    pub num_reward_distributions: u64,
+
+    pub _padding: [u64; 55],
 }
 
 impl FarmState {
@@ -170,6 +212,34 @@ impl FarmState {
         self.total_pending_stake_scaled = value.to_scaled_val().unwrap();
     }
 
+    pub fn get_total_boosted_stake_decimal(&self) -> Decimal {
+        Decimal::from_scaled_val(self.total_boosted_stake_scaled)
+    }
+
+    pub fn set_total_boosted_stake_decimal(&mut self, value: Decimal) {
+        self.total_boosted_stake_scaled = value.to_scaled_val().unwrap();
+    }
+
+    pub fn is_boost_enabled(&self) -> bool {
+        self.boost_max_multiplier_bps > consts::BPS_DIV_FACTOR && self.boost_max_lock_duration > 0
+    }
+
+    pub fn is_paused(&self, flag: PauseFlag) -> bool {
+        self.pause_flags & flag as u64 != 0
+    }
+
+    pub fn has_receipt_mint(&self) -> bool {
+        self.receipt_mint != Pubkey::default()
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.is_farm_frozen != 0
+    }
+
+    pub fn is_decommissioning(&self) -> bool {
+        self.is_decommissioning != 0
+    }
+
     pub fn is_delegated(&self) -> bool {
         self.delegate_authority != Pubkey::default()
     }
@@ -250,7 +320,9 @@ impl Default for FarmState {
             is_reward_user_once_enabled: 0,
             is_harvesting_permissionless: 0,
 
-            _padding0: [0; 3],
+            is_decommissioning: 0,
+
+            _padding0: [0; 2],
 
            
             withdraw_authority: Pubkey::default(),
@@ -284,7 +356,21 @@ impl Default for FarmState {
             vault_id: Pubkey::default(),
             second_delegated_authority: Pubkey::default(),
 
-            _padding: [0; 74],
+            boost_max_multiplier_bps: 0,
+            boost_max_lock_duration: 0,
+            total_boosted_stake_scaled: 0,
+
+            guardian: Pubkey::default(),
+            pause_flags: 0,
+
+            config_timelock_seconds: 0,
+
+            migration_target: Pubkey::default(),
+
+            receipt_mint: Pubkey::default(),
+
+            num_reward_distributions: 0,
+            _padding: [0; 55],
         }
     }
 }
@@ -334,7 +420,7 @@ impl RewardScheduleCurve {
             ts_start: 0,
             reward_per_time_unit,
         }];
-        Self::from_points(&points).unwrap()
+        Self::from_points(&points, RewardCurveMode::Step).unwrap()
     }
 
     pub fn set_constant(&mut self, rps: u64) {
@@ -345,7 +431,7 @@ impl RewardScheduleCurve {
         self.points[idx] = point;
     }
 
-    pub fn from_points(pts: &[RewardPerTimeUnitPoint]) -> Result<Self> {
+    pub fn from_points(pts: &[RewardPerTimeUnitPoint], mode: RewardCurveMode) -> Result<Self> {
         if pts.is_empty() {
             msg!("Rps curve must have at least 1 point");
             return err!(FarmError::InvalidRpsCurvePoint);
@@ -363,11 +449,11 @@ impl RewardScheduleCurve {
         points[..pts.len()].copy_from_slice(pts);
 
         let curve = RewardScheduleCurve { points };
-        curve.validate()?;
+        curve.validate(mode)?;
         Ok(curve)
     }
 
-    pub fn validate(&self) -> Result<()> {
+    pub fn validate(&self, mode: RewardCurveMode) -> Result<()> {
        
         let pts = &self.points;
 
@@ -406,9 +492,52 @@ impl RewardScheduleCurve {
             return err!(FarmError::InvalidRpsCurvePoint);
         }
 
+        if mode == RewardCurveMode::Linear {
+            for i in 0..pts.len() - 1 {
+                if pts[i + 1].ts_start == u64::MAX {
+                    break;
+                }
+                let segment_amount = (u128::from(pts[i].reward_per_time_unit)
+                    + u128::from(pts[i + 1].reward_per_time_unit))
+                    * u128::from(pts[i + 1].ts_start - pts[i].ts_start);
+                if segment_amount > u128::from(u64::MAX) {
+                    msg!("Linear rps curve segment {} issues more than u64::MAX", i);
+                    return err!(FarmError::InvalidRpsCurvePoint);
+                }
+            }
+        }
+
         Ok(())
     }
 
+    fn next_point_ts(&self, index: usize) -> Option<u64> {
+        self.points
+            .get(index + 1)
+            .map(|point| point.ts_start)
+            .filter(|ts| *ts != u64::MAX)
+    }
+
+    fn linear_segment_amount_until(&self, index: usize, ts: u64) -> Result<u128> {
+        let point = &self.points[index];
+        let elapsed = u128::from(ts - point.ts_start);
+        let rate_start = u128::from(point.reward_per_time_unit);
+        let segment_duration = match self.next_point_ts(index) {
+            Some(next_ts) => u128::from(next_ts - point.ts_start),
+            None => return Ok(rate_start * elapsed),
+        };
+        let rate_end = u128::from(self.points[index + 1].reward_per_time_unit);
+
+        let rate_sum_scaled = rate_start
+            .checked_mul(2 * segment_duration - elapsed)
+            .and_then(|v| v.checked_add(rate_end.checked_mul(elapsed)?))
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?;
+        let amount = elapsed
+            .checked_mul(rate_sum_scaled)
+            .ok_or_else(|| dbg_msg!(FarmError::IntegerOverflow))?
+            / (2 * segment_duration);
+        Ok(amount)
+    }
+
     fn most_recent_curve_starting_point(&self, last_issued_ts: u64) -> Result<usize> {
        
        
@@ -430,6 +559,7 @@ impl RewardScheduleCurve {
     }
     pub fn get_cumulative_amount_issued_since_last_ts(
         &self,
+        mode: RewardCurveMode,
         last_issued_ts: u64,
         current_ts: u64,
     ) -> Result<u64> {
@@ -473,18 +603,37 @@ impl RewardScheduleCurve {
             };
 
            
-            let period_amount = point.reward_per_time_unit * (end_ts - start_ts);
+            let period_amount = match mode {
+                RewardCurveMode::Step => point.reward_per_time_unit * (end_ts - start_ts),
+                RewardCurveMode::Linear => {
+                    let amount = self.linear_segment_amount_until(i, end_ts)?
+                        - self.linear_segment_amount_until(i, start_ts)?;
+                    u64::try_from(amount).map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?
+                }
+            };
             cumulative_amount += period_amount;
         }
 
         Ok(cumulative_amount)
     }
 
-    pub fn get_current_rps(&self, current_ts: u64) -> Result<u64> {
+    pub fn get_current_rps(&self, mode: RewardCurveMode, current_ts: u64) -> Result<u64> {
        
        
         let index = self.most_recent_curve_starting_point(current_ts)?;
-        Ok(self.points[index].reward_per_time_unit)
+        let point = &self.points[index];
+        match (mode, self.next_point_ts(index)) {
+            (RewardCurveMode::Linear, Some(next_ts)) => {
+                let rate_start = u128::from(point.reward_per_time_unit);
+                let rate_end = u128::from(self.points[index + 1].reward_per_time_unit);
+                let segment_duration = u128::from(next_ts - point.ts_start);
+                let elapsed = u128::from(current_ts - point.ts_start);
+                let rps = (rate_start * (segment_duration - elapsed) + rate_end * elapsed)
+                    / segment_duration;
+                Ok(u64::try_from(rps).map_err(|_| dbg_msg!(FarmError::IntegerOverflow))?)
+            }
+            _ => Ok(point.reward_per_time_unit),
+        }
     }
 }
 
@@ -540,7 +689,23 @@ pub struct UserState {
 
     pub rewards_issued_cumulative: [u64; MAX_REWARDS_TOKENS],
 
-    pub _padding_1: [u64; 40],
+    pub pending_withdrawal_tickets: [WithdrawalTicket; MAX_WITHDRAWAL_TICKETS],
+
+    pub pending_deposit_lots: [DepositLot; MAX_DEPOSIT_LOTS],
+
+    pub boosted_stake_scaled: u128,
+    pub lock_end_ts: u64,
+
+    pub reward_generations: [u8; MAX_REWARDS_TOKENS],
+    pub _padding_2: [u8; 6],
+
+    pub vesting_amount: [u64; MAX_REWARDS_TOKENS],
+    pub vesting_duration_remaining: [u32; MAX_REWARDS_TOKENS],
+
+    pub receipt_amount: u64,
+    pub boost_refresh_ts: u64,
+
+    pub _padding_1: [u64; 2],
 }
 
 impl UserState {
@@ -556,6 +721,10 @@ impl UserState {
         Decimal::from_scaled_val(self.pending_withdrawal_unstake_scaled)
     }
 
+    pub fn get_boosted_stake_decimal(&self) -> Decimal {
+        Decimal::from_scaled_val(self.boosted_stake_scaled)
+    }
+
     pub fn get_rewards_tally_decimal(&self, index: usize) -> Decimal {
         Decimal::from_scaled_val(self.rewards_tally_scaled[index])
     }
@@ -572,6 +741,10 @@ impl UserState {
         self.pending_withdrawal_unstake_scaled = value.to_scaled_val().unwrap();
     }
 
+    pub fn set_boosted_stake_decimal(&mut self, value: Decimal) {
+        self.boosted_stake_scaled = value.to_scaled_val().unwrap();
+    }
+
     pub fn set_rewards_tally_decimal(&mut self, index: usize, value: Decimal) {
         self.rewards_tally_scaled[index] = value.to_scaled_val().unwrap();
     }
@@ -601,11 +774,140 @@ impl Default for UserState {
             last_stake_ts: 0,
 
             rewards_issued_cumulative: [0; MAX_REWARDS_TOKENS],
-            _padding_1: [0; 40],
+            pending_withdrawal_tickets: [WithdrawalTicket::default(); MAX_WITHDRAWAL_TICKETS],
+            pending_deposit_lots: [DepositLot::default(); MAX_DEPOSIT_LOTS],
+            boosted_stake_scaled: Decimal::zero().to_scaled_val().unwrap(),
+            lock_end_ts: 0,
+            reward_generations: [0; MAX_REWARDS_TOKENS],
+            _padding_2: [0; 6],
+            vesting_amount: [0; MAX_REWARDS_TOKENS],
+            vesting_duration_remaining: [0; MAX_REWARDS_TOKENS],
+            receipt_amount: 0,
+            boost_refresh_ts: 0,
+            _padding_1: [0; 2],
         }
     }
 }
 
+static_assertions::const_assert_eq!(
+    consts::SIZE_PENDING_CONFIG_CHANGE,
+    std::mem::size_of::<PendingConfigChange>() + 8
+);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<PendingConfigChange>() % 8);
+#[account(zero_copy)]
+#[derive(Debug, Eq, PartialEq)]
+pub struct PendingConfigChange {
+    pub target: Pubkey,
+    pub admin: Pubkey,
+
+    pub queued_ts: u64,
+    pub execute_after_ts: u64,
+
+    pub target_kind: u8,
+    pub _padding0: [u8; 3],
+    pub mode: u16,
+    pub data_len: u16,
+
+    pub data: [u8; consts::PENDING_CONFIG_CHANGE_DATA_LEN],
+
+    pub _padding1: [u64; 16],
+}
+
+impl PendingConfigChange {
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.data_len as usize]
+    }
+
+    pub fn target_kind(&self) -> ConfigChangeTarget {
+        ConfigChangeTarget::try_from(self.target_kind).unwrap()
+    }
+}
+
+static_assertions::const_assert_eq!(
+    consts::SIZE_REWARD_DISTRIBUTION,
+    std::mem::size_of::<RewardDistribution>() + 8
+);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<RewardDistribution>() % 8);
+#[account(zero_copy)]
+#[derive(Debug, Eq, PartialEq)]
+pub struct RewardDistribution {
+    pub farm_state: Pubkey,
+    pub claimed_bitmap: Pubkey,
+    pub merkle_root: [u8; 32],
+
+    pub distribution_id: u64,
+    pub reward_index: u64,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub num_leaves: u64,
+    pub num_claimed: u64,
+    pub created_ts: u64,
+
+    pub reward_generation: u8,
+    pub _padding0: [u8; 7],
+
+    pub _padding1: [u64; 16],
+}
+
+impl RewardDistribution {
+    pub fn remaining_amount(&self) -> u64 {
+        self.total_amount - self.claimed_amount
+    }
+}
+
+static_assertions::const_assert_eq!(
+    consts::SIZE_CLAIMED_BITMAP_HEADER,
+    std::mem::size_of::<ClaimedBitmap>() + 8
+);
+#[account(zero_copy)]
+#[derive(Debug, Eq, PartialEq)]
+pub struct ClaimedBitmap {
+    pub reward_distribution: Pubkey,
+}
+
+impl ClaimedBitmap {
+    pub fn space(num_leaves: u64) -> usize {
+        consts::SIZE_CLAIMED_BITMAP_HEADER + (num_leaves as usize).div_ceil(8)
+    }
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug,
+)]
+#[repr(u8)]
+pub enum ConfigChangeTarget {
+    FarmState = 0,
+    GlobalConfig = 1,
+}
+
+#[zero_copy]
+#[repr(C)]
+#[derive(Debug, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawalTicket {
+    pub amount: u64,
+    pub maturity_ts: u64,
+}
+
+impl WithdrawalTicket {
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0
+    }
+}
+
+#[zero_copy]
+#[repr(C)]
+#[derive(Debug, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct DepositLot {
+    pub amount: u64,
+    pub activation_ts: u64,
+}
+
+impl DepositLot {
+    pub fn is_empty(&self) -> bool {
+        self.amount == 0
+    }
+}
+
 #[zero_copy]
 #[repr(C)]
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -625,12 +927,27 @@ pub struct RewardInfo {
 
     pub reward_type: u8,
     pub rewards_per_second_decimals: u8,
+    pub generation: u8,
+    pub reward_curve_mode: u8,
+    pub forfeit_vesting_on_unstake: u8,
 
-    pub _padding0: [u8; 6],
-    pub _padding1: [u64; 20],
+    pub _padding0: [u8; 3],
+    pub retire_grace_end_ts: u64,
+    pub vesting_duration: u64,
+    pub rewards_vesting: u64,
+    pub rewards_distribution_reserved: u64,
+    pub _padding1: [u64; 16],
 }
 
 impl RewardInfo {
+    pub fn is_free(&self) -> bool {
+        self.token.mint == Pubkey::default()
+    }
+
+    pub fn is_retiring(&self) -> bool {
+        self.retire_grace_end_ts != 0
+    }
+
     pub fn get_reward_per_share_decimal(&self) -> Decimal {
         Decimal::from_scaled_val(self.reward_per_share_scaled)
     }
@@ -688,6 +1005,25 @@ pub enum FarmConfigOption {
     UpdateIsRewardUserOnceEnabled,
     UpdateDelegatedAuthority,
     UpdateIsHarvestingPermissionless,
+    UpdateBoostMaxMultiplierBps,
+    UpdateBoostMaxLockDuration,
+    UpdateFarmGuardian,
+    UpdateConfigTimelock,
+    UpdateRewardVestingDuration,
+    UpdateMigrationTarget,
+    UpdateRewardCurveMode,
+    UpdateRewardForfeitVestingOnUnstake,
+}
+
+impl FarmConfigOption {
+    pub fn is_timelocked(&self) -> bool {
+        !matches!(
+            self,
+            FarmConfigOption::UpdateRewardRps
+                | FarmConfigOption::UpdateStrategyId
+                | FarmConfigOption::UpdateVaultId
+        )
+    }
 }
 
 #[derive(
@@ -708,6 +1044,15 @@ pub enum RewardType {
     Constant = 1,
 }
 
+#[derive(
+    AnchorSerialize, AnchorDeserialize, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug,
+)]
+#[repr(u8)]
+pub enum RewardCurveMode {
+    Step = 0,
+    Linear = 1,
+}
+
 #[derive(
     AnchorSerialize, AnchorDeserialize, TryFromPrimitive, PartialEq, Eq, Clone, Copy, Debug,
 )]
@@ -718,6 +1063,24 @@ pub enum LockingMode {
     WithExpiry = 2,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u64)]
+pub enum PauseFlag {
+    Stake = 1 << 0,
+    Unstake = 1 << 1,
+    Harvest = 1 << 2,
+    AddRewards = 1 << 3,
+    RewardIssuance = 1 << 4,
+}
+
+impl PauseFlag {
+    pub const ALL: u64 = PauseFlag::Stake as u64
+        | PauseFlag::Unstake as u64
+        | PauseFlag::Harvest as u64
+        | PauseFlag::AddRewards as u64
+        | PauseFlag::RewardIssuance as u64;
+}
+
 impl Default for LockingMode {
     fn default() -> LockingMode {
         LockingMode::None
@@ -746,4 +1109,117 @@ impl RewardInfo {
     pub fn reward_type(&self) -> RewardType {
         RewardType::try_from(self.reward_type).unwrap()
     }
+
+    pub fn reward_curve_mode(&self) -> RewardCurveMode {
+        RewardCurveMode::try_from(self.reward_curve_mode).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_point_curve(mode: RewardCurveMode) -> RewardScheduleCurve {
+        RewardScheduleCurve::from_points(
+            &[
+                RewardPerTimeUnitPoint::new(100, 10),
+                RewardPerTimeUnitPoint::new(200, 30),
+            ],
+            mode,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_linear_full_segment() {
+        let curve = two_point_curve(RewardCurveMode::Linear);
+        let amount = curve
+            .get_cumulative_amount_issued_since_last_ts(RewardCurveMode::Linear, 100, 200)
+            .unwrap();
+        assert_eq!(amount, 2000);
+    }
+
+    #[test]
+    fn test_linear_partial_segment() {
+        let curve = two_point_curve(RewardCurveMode::Linear);
+        let amount = curve
+            .get_cumulative_amount_issued_since_last_ts(RewardCurveMode::Linear, 100, 150)
+            .unwrap();
+        assert_eq!(amount, 750);
+        let amount = curve
+            .get_cumulative_amount_issued_since_last_ts(RewardCurveMode::Linear, 150, 200)
+            .unwrap();
+        assert_eq!(amount, 1250);
+        assert_eq!(
+            curve.get_current_rps(RewardCurveMode::Linear, 150).unwrap(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_linear_across_segment_boundary() {
+        let curve = two_point_curve(RewardCurveMode::Linear);
+        let amount = curve
+            .get_cumulative_amount_issued_since_last_ts(RewardCurveMode::Linear, 150, 250)
+            .unwrap();
+        assert_eq!(amount, 1250 + 30 * 50);
+        assert_eq!(
+            curve.get_current_rps(RewardCurveMode::Linear, 200).unwrap(),
+            30
+        );
+        assert_eq!(
+            curve.get_current_rps(RewardCurveMode::Linear, 300).unwrap(),
+            30
+        );
+    }
+
+    #[test]
+    fn test_linear_split_intervals_sum_to_total() {
+        let curve = two_point_curve(RewardCurveMode::Linear);
+        let mut total = 0;
+        let mut last_ts = 100;
+        for ts in [101, 137, 171, 199, 200, 233, 260] {
+            total += curve
+                .get_cumulative_amount_issued_since_last_ts(RewardCurveMode::Linear, last_ts, ts)
+                .unwrap();
+            last_ts = ts;
+        }
+        let expected = curve
+            .get_cumulative_amount_issued_since_last_ts(RewardCurveMode::Linear, 100, 260)
+            .unwrap();
+        assert_eq!(total, expected);
+    }
+
+    #[test]
+    fn test_step_vs_linear_totals() {
+        let step_curve = two_point_curve(RewardCurveMode::Step);
+        let linear_curve = two_point_curve(RewardCurveMode::Linear);
+        let step = step_curve
+            .get_cumulative_amount_issued_since_last_ts(RewardCurveMode::Step, 100, 300)
+            .unwrap();
+        let linear = linear_curve
+            .get_cumulative_amount_issued_since_last_ts(RewardCurveMode::Linear, 100, 300)
+            .unwrap();
+        assert_eq!(step, 10 * 100 + 30 * 100);
+        assert_eq!(linear, 2000 + 30 * 100);
+        assert_eq!(
+            step_curve
+                .get_current_rps(RewardCurveMode::Step, 150)
+                .unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_linear_segment_overflow_rejected() {
+        let points = [
+            RewardPerTimeUnitPoint::new(0, u64::MAX),
+            RewardPerTimeUnitPoint::new(10, u64::MAX),
+        ];
+        assert!(RewardScheduleCurve::from_points(&points, RewardCurveMode::Step).is_ok());
+        assert_eq!(
+            RewardScheduleCurve::from_points(&points, RewardCurveMode::Linear).unwrap_err(),
+            FarmError::InvalidRpsCurvePoint.into()
+        );
+    }
 }
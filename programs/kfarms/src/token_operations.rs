@@ -1,29 +1,13 @@
-use anchor_lang::prelude::{msg, AccountInfo, CpiContext, Result};
+use anchor_lang::{
+    prelude::{msg, AccountInfo, CpiContext, Result},
+    solana_program::program::invoke,
+};
 use anchor_spl::{
-    token::{self, Transfer},
-    token_interface::TransferChecked,
+    token_2022::spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint,
+    token_interface::{Burn, CloseAccount, MintTo, TransferChecked},
 };
 
-use crate::utils::accessors::mint_decimals;
-
-#[allow(clippy::too_many_arguments)]
-pub fn transfer_from_vault<'info>(
-    amount: u64,
-    signer: &[&[&[u8]]],
-    to_vault: &AccountInfo<'info>,
-    from_vault: &AccountInfo<'info>,
-    from_vault_authority: &AccountInfo<'info>,
-    token_program: &AccountInfo<'info>,
-) -> Result<()> {
-    let cpi_transfer_accounts = Transfer {
-        from: from_vault.clone(),
-        to: to_vault.clone(),
-        authority: from_vault_authority.clone(),
-    };
-
-    let cpi_ctx = CpiContext::new(token_program.clone(), cpi_transfer_accounts).with_signer(signer);
-    token::transfer(cpi_ctx, amount)
-}
+use crate::utils::accessors::{mint_decimals, token_account_withheld_fee};
 
 #[allow(clippy::too_many_arguments)]
 pub fn transfer_2022_from_vault<'info>(
@@ -52,15 +36,88 @@ pub fn transfer_from_user<'info>(
     to: &AccountInfo<'info>,
     authority: &AccountInfo<'info>,
     token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
 ) -> Result<()> {
-    let cpi_transfer_accounts = Transfer {
+    let cpi_transfer_accounts = TransferChecked {
         from: from_ata.clone(),
         to: to.clone(),
         authority: authority.clone(),
+        mint: mint.clone(),
     };
     let cpi_ctx = CpiContext::new(token_program.clone(), cpi_transfer_accounts);
 
-    let result = token::transfer(cpi_ctx, amount);
+    let result = anchor_spl::token_2022::transfer_checked(cpi_ctx, amount, mint_decimals(mint)?);
     msg!("Transferred {:?}", result);
     result
 }
+
+pub fn harvest_withheld_fees_to_mint<'info>(
+    vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let withheld_fee = token_account_withheld_fee(vault)?;
+    if withheld_fee == 0 {
+        return Ok(());
+    }
+    msg!(
+        "harvesting withheld fees {} from vault {:?} to mint",
+        withheld_fee,
+        vault.key
+    );
+    let ix = harvest_withheld_tokens_to_mint(token_program.key, mint.key, &[vault.key])?;
+    invoke(&ix, &[mint.clone(), vault.clone(), token_program.clone()])?;
+    Ok(())
+}
+
+pub fn close_vault<'info>(
+    signer: &[&[&[u8]]],
+    vault: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    vault_authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let cpi_close_accounts = CloseAccount {
+        account: vault.clone(),
+        destination: destination.clone(),
+        authority: vault_authority.clone(),
+    };
+
+    let cpi_ctx = CpiContext::new(token_program.clone(), cpi_close_accounts).with_signer(signer);
+    anchor_spl::token_interface::close_account(cpi_ctx)
+}
+
+pub fn mint_receipt<'info>(
+    amount: u64,
+    signer: &[&[&[u8]]],
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    mint_authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let cpi_mint_accounts = MintTo {
+        mint: mint.clone(),
+        to: to.clone(),
+        authority: mint_authority.clone(),
+    };
+
+    let cpi_ctx = CpiContext::new(token_program.clone(), cpi_mint_accounts).with_signer(signer);
+    anchor_spl::token_interface::mint_to(cpi_ctx, amount)
+}
+
+pub fn burn_receipt<'info>(
+    amount: u64,
+    mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let cpi_burn_accounts = Burn {
+        mint: mint.clone(),
+        from: from.clone(),
+        authority: authority.clone(),
+    };
+
+    let cpi_ctx = CpiContext::new(token_program.clone(), cpi_burn_accounts);
+    anchor_spl::token_interface::burn(cpi_ctx, amount)
+}
@@ -1,10 +1,20 @@
-#[derive(Debug)]
+use anchor_lang::prelude::*;
+use decimal_wad::decimal::Decimal;
+
+use crate::{
+    state::{DepositLot, WithdrawalTicket},
+    utils::consts::{MAX_DEPOSIT_LOTS, MAX_REWARDS_TOKENS, MAX_WITHDRAWAL_TICKETS},
+    UserState,
+};
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
 pub struct HarvestEffects {
     pub reward_user: u64,
     pub reward_treasury: u64,
+    pub reward_vested: u64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
 pub struct WithdrawEffects {
     pub amount_to_withdraw: u64,
 }
@@ -17,17 +27,104 @@ pub struct WithdrawRewardEffects {
     pub reward_amount: u64,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+pub struct RetireRewardEffects {
+    pub reward_amount: u64,
+    pub slot_freed: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
 pub struct StakeEffects {
     pub amount_to_stake: u64,
 }
 
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct UnstakeEffects {
+    pub stake_shares_unstaked_scaled: u128,
+    pub amount_unstaked: u64,
+    pub penalty_amount: u64,
+}
+
+#[derive(Debug)]
+pub struct TransferPositionEffects {
+    pub stake_transferred: Decimal,
+    pub amount_transferred: u64,
+    pub full_transfer: bool,
+}
+
 #[derive(Debug)]
 pub struct VaultWithdrawEffects {
     pub amount_to_withdraw: u64,
     pub farm_to_freeze: bool,
 }
 
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct UserStakeReturnData {
+    pub active_stake_scaled: u128,
+    pub pending_deposit_stake_scaled: u128,
+    pub pending_withdrawal_unstake_scaled: u128,
+    pub rewards_issued_unclaimed: [u64; MAX_REWARDS_TOKENS],
+}
+
+impl From<&UserState> for UserStakeReturnData {
+    fn from(user_state: &UserState) -> Self {
+        Self {
+            active_stake_scaled: user_state.active_stake_scaled,
+            pending_deposit_stake_scaled: user_state.pending_deposit_stake_scaled,
+            pending_withdrawal_unstake_scaled: user_state.pending_withdrawal_unstake_scaled,
+            rewards_issued_unclaimed: user_state.rewards_issued_unclaimed,
+        }
+    }
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct StakeReturnData {
+    pub effects: StakeEffects,
+    pub user_stake: UserStakeReturnData,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct UnstakeReturnData {
+    pub effects: UnstakeEffects,
+    pub user_stake: UserStakeReturnData,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct HarvestReturnData {
+    pub effects: HarvestEffects,
+    pub user_stake: UserStakeReturnData,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawReturnData {
+    pub effects: WithdrawEffects,
+    pub user_stake: UserStakeReturnData,
+}
+
+#[derive(Debug, Default, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct PendingRewardReturnData {
+    pub claimable_user: u64,
+    pub claimable_now: u64,
+    pub treasury_fee: u64,
+    pub newly_vested: u64,
+    pub vesting_amount: u64,
+    pub time_until_claimable: u64,
+}
+
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct UserPendingRewardsReturnData {
+    pub ts: u64,
+    pub rewards: [PendingRewardReturnData; MAX_REWARDS_TOKENS],
+    pub active_stake_scaled: u128,
+    pub locked_stake_scaled: u128,
+    pub lock_end_ts: u64,
+    pub pending_deposit_stake_scaled: u128,
+    pub pending_deposit_stake_ts: u64,
+    pub pending_deposit_lots: [DepositLot; MAX_DEPOSIT_LOTS],
+    pub pending_withdrawal_unstake_scaled: u128,
+    pub pending_withdrawal_unstake_ts: u64,
+    pub pending_withdrawal_tickets: [WithdrawalTicket; MAX_WITHDRAWAL_TICKETS],
+}
+
 pub enum AccountLoaderState {
     Zeroed,
     Initialized,
@@ -1,4 +1,13 @@
-use anchor_lang::{prelude::AccountInfo, Result, ToAccountInfo};
+use anchor_lang::{error, prelude::AccountInfo, Result, ToAccountInfo};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{Account, Mint},
+};
+
+use crate::FarmError;
 
 pub fn mint_decimals(account: &AccountInfo) -> Result<u8> {
     let bytes = account.try_borrow_data()?;
@@ -7,6 +16,26 @@ pub fn mint_decimals(account: &AccountInfo) -> Result<u8> {
     Ok(u8::from_le_bytes(amount_bytes))
 }
 
+pub fn mint_transfer_fee(account: &AccountInfo, amount: u64, epoch: u64) -> Result<u64> {
+    let bytes = account.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&bytes)?;
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or_else(|| error!(FarmError::IntegerOverflow)),
+        Err(_) => Ok(0),
+    }
+}
+
+pub fn token_account_withheld_fee(account: &AccountInfo) -> Result<u64> {
+    let bytes = account.try_borrow_data()?;
+    let token_account = StateWithExtensions::<Account>::unpack(&bytes)?;
+    match token_account.get_extension::<TransferFeeAmount>() {
+        Ok(transfer_fee_amount) => Ok(u64::from(transfer_fee_amount.withheld_amount)),
+        Err(_) => Ok(0),
+    }
+}
+
 pub fn account_discriminator(account: &dyn ToAccountInfo) -> Result<[u8; 8]> {
     let account = account.to_account_info();
     let data = account.try_borrow_data()?;
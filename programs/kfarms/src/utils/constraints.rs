@@ -1,10 +1,11 @@
 use anchor_lang::{
     err,
-    prelude::{Context, Result},
+    prelude::{Context, Pubkey, Result},
     Bumps,
 };
+use anchor_spl::associated_token::get_associated_token_address;
 
-use crate::FarmError;
+use crate::{FarmError, FarmState};
 
 pub fn check_remaining_accounts<T>(ctx: &Context<T>) -> Result<()>
 where
@@ -18,6 +19,21 @@ where
     Ok(())
 }
 
+pub fn check_receipt_accounts(
+    farm_state: &FarmState,
+    owner: &Pubkey,
+    receipt_mint: &Pubkey,
+    user_receipt_ata: &Pubkey,
+) -> Result<()> {
+    if *receipt_mint != farm_state.receipt_mint
+        || *user_receipt_ata != get_associated_token_address(owner, &farm_state.receipt_mint)
+    {
+        return err!(FarmError::UnexpectedAccount);
+    }
+
+    Ok(())
+}
+
 pub mod token_2022 {
     use anchor_lang::{
         err,
@@ -47,10 +63,17 @@ pub mod token_2022 {
     pub fn validate_reward_token_extensions(
         mint_acc_info: &AccountInfo,
     ) -> anchor_lang::Result<()> {
-        validate_base_token_extensions(mint_acc_info)
+        validate_token_extensions(mint_acc_info, true)
     }
 
     pub fn validate_base_token_extensions(mint_acc_info: &AccountInfo) -> anchor_lang::Result<()> {
+        validate_token_extensions(mint_acc_info, false)
+    }
+
+    fn validate_token_extensions(
+        mint_acc_info: &AccountInfo,
+        allow_transfer_fee: bool,
+    ) -> anchor_lang::Result<()> {
         let mint_data = mint_acc_info.data.borrow();
         let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
         for mint_ext in mint.get_extension_types()? {
@@ -62,7 +85,7 @@ pub mod token_2022 {
                 );
                 return err!(FarmError::UnsupportedTokenExtension);
             }
-            if mint_ext == ExtensionType::TransferFeeConfig {
+            if mint_ext == ExtensionType::TransferFeeConfig && !allow_transfer_fee {
                 let ext = mint
                     .get_extension::<spl_token_2022::extension::transfer_fee::TransferFeeConfig>(
                     )?;
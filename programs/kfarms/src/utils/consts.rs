@@ -1,6 +1,12 @@
 pub const MAX_REWARDS_TOKENS: usize = 10;
 pub const REWARD_CURVE_POINTS: usize = 20;
+pub const MAX_WITHDRAWAL_TICKETS: usize = 4;
+pub const MAX_DEPOSIT_LOTS: usize = 4;
 pub const BPS_DIV_FACTOR: u64 = 10_000;
+pub const PENDING_CONFIG_CHANGE_DATA_LEN: usize = 32;
+pub const MAX_CLAIMED_BITMAP_SPACE: usize = 10240;
+pub const MAX_DISTRIBUTION_LEAVES: u64 =
+    ((MAX_CLAIMED_BITMAP_SPACE - SIZE_CLAIMED_BITMAP_HEADER) * 8) as u64;
 
 pub const BASE_SEED_FARM_VAULT: &[u8; 6] = b"fvault";
 pub const BASE_SEED_REWARD_VAULT: &[u8; 6] = b"rvault";
@@ -8,8 +14,15 @@ pub const BASE_SEED_REWARD_TREASURY_VAULT: &[u8; 6] = b"tvault";
 pub const BASE_SEED_FARM_VAULTS_AUTHORITY: &[u8; 9] = b"authority";
 pub const BASE_SEED_TREASURY_VAULTS_AUTHORITY: &[u8; 9] = b"authority";
 pub const BASE_SEED_USER_STATE: &[u8; 4] = b"user";
+pub const BASE_SEED_PENDING_CONFIG_CHANGE: &[u8; 14] = b"pending_config";
+pub const BASE_SEED_REWARD_DISTRIBUTION: &[u8; 12] = b"distribution";
+pub const BASE_SEED_CLAIMED_BITMAP: &[u8; 14] = b"claimed_bitmap";
+pub const BASE_SEED_RECEIPT_MINT: &[u8; 12] = b"receipt_mint";
 
 
 pub const SIZE_GLOBAL_CONFIG: usize = 2136;
 pub const SIZE_FARM_STATE: usize = 8336;
 pub const SIZE_USER_STATE: usize = 920;
+pub const SIZE_PENDING_CONFIG_CHANGE: usize = 256;
+pub const SIZE_REWARD_DISTRIBUTION: usize = 296;
+pub const SIZE_CLAIMED_BITMAP_HEADER: usize = 40;
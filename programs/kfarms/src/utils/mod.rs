@@ -3,5 +3,6 @@ pub mod constraints;
 pub mod consts;
 pub mod macros;
 pub mod math;
+pub mod merkle;
 pub mod scope;
 pub mod withdrawal_penalty;